@@ -71,6 +71,11 @@ mod build_common {
         println!("cargo:rerun-if-changed=./did/*");
 
         let is_dev = dotenv!("BACKEND") == "LOCAL";
+        // Gates what only a local replica build may contain, like the dev identity
+        println!("cargo:rustc-check-cfg=cfg(local_backend)");
+        if is_dev {
+            println!("cargo:rustc-cfg=local_backend");
+        }
 
         let mut candid_config: candid_parser::bindings::rust::Config = candid_parser::bindings::rust::Config::new();
         candid_config.set_target(candid_parser::bindings::rust::Target::Agent);
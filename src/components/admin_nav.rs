@@ -0,0 +1,25 @@
+use leptos::*;
+
+/// Links between the admin pages
+#[component]
+pub fn AdminNav() -> impl IntoView {
+    view! {
+        <nav class="flex gap-4 mb-4 text-sm font-medium">
+            <a href="/admin/permissions" class="text-blue-500 underline">
+                "Permissions"
+            </a>
+            <a href="/admin/proposals" class="text-blue-500 underline">
+                "Proposals"
+            </a>
+            <a href="/admin/configuration" class="text-blue-500 underline">
+                "Configuration"
+            </a>
+            <a href="/admin/ownership" class="text-blue-500 underline">
+                "Ownership"
+            </a>
+            <a href="/admin/reviews" class="text-blue-500 underline">
+                "Reviews"
+            </a>
+        </nav>
+    }
+}
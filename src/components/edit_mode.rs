@@ -0,0 +1,54 @@
+use crate::outbound::asset_properties::PropertyEdit;
+use anyhow::Error;
+use leptos::*;
+
+/// Selected action for a single `opt opt` field in an editor
+#[derive(Clone, Copy, PartialEq)]
+pub enum EditMode {
+    Unchanged,
+    Clear,
+    Set,
+}
+
+impl EditMode {
+    fn from_value(value: &str) -> Self {
+        match value {
+            "clear" => EditMode::Clear,
+            "set" => EditMode::Set,
+            _ => EditMode::Unchanged,
+        }
+    }
+
+    /// Build the edit for this mode, parsing the input only when a value is set
+    pub fn edit<T>(
+        self,
+        value: impl FnOnce() -> Result<T, Error>,
+    ) -> Result<PropertyEdit<T>, Error> {
+        Ok(match self {
+            EditMode::Unchanged => PropertyEdit::Unchanged,
+            EditMode::Clear => PropertyEdit::Clear,
+            EditMode::Set => PropertyEdit::Set(value()?),
+        })
+    }
+}
+
+/// Leave unchanged / clear / set picker for an `opt opt` field
+#[component]
+pub fn EditModeSelect(mode: RwSignal<EditMode>) -> impl IntoView {
+    view! {
+        <select
+            class="rounded-md border-gray-300"
+            on:change=move |e| mode.set(EditMode::from_value(&event_target_value(&e)))
+        >
+            <option value="unchanged" selected=move || mode.get() == EditMode::Unchanged>
+                "Leave unchanged"
+            </option>
+            <option value="clear" selected=move || mode.get() == EditMode::Clear>
+                "Clear"
+            </option>
+            <option value="set" selected=move || mode.get() == EditMode::Set>
+                "Set"
+            </option>
+        </select>
+    }
+}
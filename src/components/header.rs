@@ -1,11 +1,10 @@
+use crate::components::session_expiry::SessionExpiryBanner;
 use crate::state::{
-    auth::AuthService,
+    auth::use_auth,
     auth_actions::{create_login_action, create_logout_action},
+    identity_provider::IdentityProvider,
 };
 use leptos::*;
-use leptos_dom::logging::{console_error, console_log};
-use std::cell::RefCell;
-use std::rc::Rc;
 #[component]
 pub fn Header() -> impl IntoView {
     view! {
@@ -23,6 +22,9 @@ pub fn Header() -> impl IntoView {
 
                     <span class="font-medium text-black">"Collections"</span>
                 </a>
+                <a href="/admin/permissions">
+                    <span class="font-medium text-black">"Admin"</span>
+                </a>
                 <UserPrincipal />
             // <button class="p-2 text-white bg-black rounded-full">
             // <svg xmlns="http://www.w3.org/2000/svg" class="w-6 h-6" fill="none" viewBox="0 0 24 24" stroke="currentColor" stroke-width="2">
@@ -31,44 +33,58 @@ pub fn Header() -> impl IntoView {
             // </button>
             </div>
         </div>
+        <SessionExpiryBanner />
     }
 }
 
 #[component]
 fn UserPrincipal() -> impl IntoView {
-    // Obtain AuthService from the context
-    let auth_service =
-        use_context::<Rc<RefCell<AuthService>>>().expect("AuthService context must be provided");
-
-    // Reactive signal for authentication state
-    let is_authenticated = create_memo({
-        let auth_service = Rc::clone(&auth_service);
-        move |_| auth_service.borrow().is_authenticated()
-    });
-
-    // Reactive signal for principal
-    let principal = create_memo({
-        let auth_service = Rc::clone(&auth_service);
-        move |_| {
-            if is_authenticated() {
-                auth_service.borrow().get_principal().ok()
-            } else {
-                None
-            }
-        }
-    });
+    let auth = use_auth();
+    let is_authenticated = auth.is_authenticated;
+    let principal = auth.principal;
 
     // Use the reusable actions from auth_actions.rs
-    let handle_login = create_login_action(Rc::clone(&auth_service));
-    let handle_logout = create_logout_action(Rc::clone(&auth_service));
+    let handle_login = create_login_action(auth);
+    let handle_logout = create_logout_action(auth);
+    // Picked in the header before logging in, the last used one until then
+    let (picked, set_picked) = create_signal(None::<IdentityProvider>);
+    let provider = Signal::derive(move || picked.get().unwrap_or_else(|| auth.provider()));
     view! {
         <Show
             when=move || is_authenticated()
             fallback=move || {
                 view! {
+                    <select
+                        on:change=move |e| {
+                            let label = event_target_value(&e);
+                            if let Some(selected) = IdentityProvider::available()
+                                .into_iter()
+                                .find(|provider| provider.label() == label)
+                            {
+                                set_picked.set(Some(selected));
+                            }
+                        }
+                        class="py-1 text-sm rounded-md border-gray-300"
+                        aria-label="Identity provider"
+                    >
+                        {IdentityProvider::available()
+                            .into_iter()
+                            .map(|option| {
+                                view! {
+                                    <option
+                                        value=option.label()
+                                        selected=move || provider.get() == option
+                                    >
+                                        {option.label()}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
                     <button
-                        on:click=move |_| handle_login.dispatch(())
+                        on:click=move |_| handle_login.dispatch(provider.get_untracked())
                         class="p-2 text-white bg-black rounded-full"
+                        aria-label="Log in"
                     >
                         <svg
                             xmlns="http://www.w3.org/2000/svg"
@@ -91,7 +107,27 @@ fn UserPrincipal() -> impl IntoView {
             <div>{"user: "}{move || principal().map(|p| p.to_text()).unwrap_or_default()}</div>
 
             <div class="flex items-center space-x-2">
-
+                <button
+                    on:click=move |_| auth.lock()
+                    class="p-2 text-white bg-gray-700 rounded-full"
+                    aria-label="Lock session"
+                    title="Lock session"
+                >
+                    <svg
+                        xmlns="http://www.w3.org/2000/svg"
+                        fill="none"
+                        viewBox="0 0 24 24"
+                        stroke-width="1.5"
+                        stroke="currentColor"
+                        class="w-4 h-4"
+                    >
+                        <path
+                            stroke-linecap="round"
+                            stroke-linejoin="round"
+                            d="M16.5 10.5V6.75a4.5 4.5 0 1 0-9 0v3.75m-.75 11.25h10.5a2.25 2.25 0 0 0 2.25-2.25v-6.75a2.25 2.25 0 0 0-2.25-2.25H6.75a2.25 2.25 0 0 0-2.25 2.25v6.75a2.25 2.25 0 0 0 2.25 2.25Z"
+                        />
+                    </svg>
+                </button>
                 <button
                     on:click=move |_| handle_logout.dispatch(())
                     class="p-2 text-white bg-red-500 rounded-full"
@@ -0,0 +1,305 @@
+use crate::components::verified_badge::VerifiedBadge;
+use crate::state::collection::CollectionImage;
+use crate::utils::asset_url::asset_url;
+use candid::Principal;
+use leptos::*;
+
+/// Move the item at `from` so it ends up at `to`, shifting the ones in between
+pub fn move_item<T>(items: &mut Vec<T>, from: usize, to: usize) {
+    if from < items.len() && to < items.len() && from != to {
+        let item = items.remove(from);
+        items.insert(to, item);
+    }
+}
+
+/// Collection images in their stored order, with a full-screen lightbox.
+///
+/// With `on_change` the images can be reordered by dragging, removed and given a
+/// caption and alt text, and with `on_set_cover` any of them can be made the
+/// collection's logo.
+#[component]
+pub fn ImageGallery(
+    #[prop(into)] images: MaybeSignal<Vec<CollectionImage>>,
+    /// Canister holding the images, the shared one when unset
+    #[prop(into)]
+    canister_id: MaybeSignal<Option<Principal>>,
+    /// Key of the current cover image
+    #[prop(into, optional)]
+    cover: MaybeSignal<String>,
+    /// Called with the new images after a reorder, removal or text edit
+    #[prop(optional, into)]
+    on_change: Option<Callback<Vec<CollectionImage>>>,
+    #[prop(optional, into)] on_set_cover: Option<Callback<String>>,
+) -> impl IntoView {
+    let images = Signal::derive(move || images.get());
+    let canister_id = Signal::derive(move || canister_id.get());
+    let cover = Signal::derive(move || cover.get());
+    let dragged = create_rw_signal(None::<String>);
+    let lightbox = create_rw_signal(None::<usize>);
+    let editable = on_change.is_some();
+
+    let index_of = move |key: &str| {
+        images.with_untracked(|images| images.iter().position(|image| image.key == key))
+    };
+    let drop_on = move |target: String| {
+        let (Some(on_change), Some(source)) = (on_change, dragged.get_untracked()) else {
+            return;
+        };
+        dragged.set(None);
+        if let (Some(from), Some(to)) = (index_of(&source), index_of(&target)) {
+            let mut reordered = images.get_untracked();
+            move_item(&mut reordered, from, to);
+            on_change.call(reordered);
+        }
+    };
+    let remove = move |key: String| {
+        if let Some(on_change) = on_change {
+            let mut remaining = images.get_untracked();
+            remaining.retain(|image| image.key != key);
+            on_change.call(remaining);
+        }
+    };
+    let edit = move |key: &str, update: &dyn Fn(&mut CollectionImage)| {
+        if let (Some(on_change), Some(index)) = (on_change, index_of(key)) {
+            let mut edited = images.get_untracked();
+            update(&mut edited[index]);
+            on_change.call(edited);
+        }
+    };
+
+    // Keyboard navigation while the lightbox is open
+    let step = move |delta: isize| {
+        let count = images.with_untracked(Vec::len) as isize;
+        if count > 0 {
+            lightbox.update(|open| {
+                if let Some(index) = open {
+                    *index = (*index as isize + delta).rem_euclid(count) as usize;
+                }
+            });
+        }
+    };
+    let keydown = window_event_listener(ev::keydown, move |e| {
+        if lightbox.get_untracked().is_none() {
+            return;
+        }
+        match e.key().as_str() {
+            "ArrowLeft" => step(-1),
+            "ArrowRight" => step(1),
+            "Escape" => lightbox.set(None),
+            _ => return,
+        }
+        e.prevent_default();
+    });
+    on_cleanup(move || keydown.remove());
+
+    view! {
+        <For
+            each=move || images.get()
+            key=|image| image.key.clone()
+            let:image
+        >
+            {
+                let key = image.key.clone();
+                // Position and text follow reorders and edits without re-rendering the item
+                let current = {
+                    let key = key.clone();
+                    create_memo(move |_| {
+                        images.with(|images| {
+                            images
+                                .iter()
+                                .position(|image| image.key == key)
+                                .map(|index| (index, images[index].clone()))
+                        })
+                    })
+                };
+                let index = move || current.with(|c| c.as_ref().map_or(0, |(index, _)| *index));
+                let alt = move || {
+                    current.with(|c| c.as_ref().map(|(index, image)| image.alt_text(*index)))
+                };
+                let caption = move || {
+                    current.with(|c| c.as_ref().map(|(_, image)| image.caption.clone()))
+                };
+                let drop_key = key.clone();
+                let drag_key = key.clone();
+                let cover_key = key.clone();
+                let is_cover = {
+                    let key = key.clone();
+                    move || cover.with(|cover| cover == &key)
+                };
+                view! {
+                    <figure class="flex flex-col gap-1 w-52 shrink-0">
+                    <div
+                        class=move || {
+                            format!(
+                                "relative p-1 w-52 h-52 rounded-md border {}",
+                                if dragged.with(|d| d.as_ref() == Some(&drag_key)) { "opacity-50" } else { "" },
+                            )
+                        }
+                        draggable=editable.then_some("true")
+                        on:dragstart={
+                            let key = key.clone();
+                            move |_| dragged.set(Some(key.clone()))
+                        }
+                        on:dragend=move |_| dragged.set(None)
+                        on:dragover=move |e| e.prevent_default()
+                        on:drop=move |e| {
+                            e.prevent_default();
+                            drop_on(drop_key.clone());
+                        }
+                    >
+                        <button
+                            type="button"
+                            on:click=move |_| lightbox.set(Some(index()))
+                            class="w-full h-full"
+                        >
+                            <img
+                                src=asset_url(canister_id.get_untracked(), &key)
+                                class="object-contain w-full h-full rounded-md"
+                                alt=alt
+                            />
+                        </button>
+                        {editable
+                            .then(|| {
+                                let key = key.clone();
+                                view! {
+                                    <button
+                                        type="button"
+                                        on:click={
+                                            let key = key.clone();
+                                            move |_| remove(key.clone())
+                                        }
+                                        class="flex absolute top-2 right-2 justify-center items-center w-4 h-4 bg-white rounded-full"
+                                        aria-label=format!("Remove image {}", key)
+                                    >
+                                        "X"
+                                    </button>
+                                }
+                            })}
+                        <div class="flex absolute bottom-2 left-2 gap-2 items-center">
+                            <VerifiedBadge asset_key=key.clone() canister_id=canister_id.get_untracked() />
+                            <a
+                                href=format!(
+                                    "/assets{}{}",
+                                    key,
+                                    canister_id
+                                        .get_untracked()
+                                        .map(|c| format!("?canister={}", c))
+                                        .unwrap_or_default(),
+                                )
+                                class="text-xs text-blue-500 underline"
+                            >
+                                "Properties"
+                            </a>
+                            {on_set_cover
+                                .map(|on_set_cover| {
+                                    let is_cover = is_cover.clone();
+                                    view! {
+                                        <button
+                                            type="button"
+                                            on:click=move |_| on_set_cover.call(cover_key.clone())
+                                            disabled=is_cover.clone()
+                                            class="text-xs text-blue-500 underline disabled:text-gray-500 disabled:no-underline"
+                                        >
+                                            {move || if is_cover() { "Cover" } else { "Set as cover" }}
+                                        </button>
+                                    }
+                                })}
+                        </div>
+                    </div>
+                    {if editable {
+                        let caption_key = key.clone();
+                        let alt_key = key.clone();
+                        view! {
+                            <input
+                                type="text"
+                                value=image.caption.clone()
+                                on:change=move |e| {
+                                    let caption = event_target_value(&e);
+                                    edit(&caption_key, &|image| image.caption = caption.clone());
+                                }
+                                class="text-xs rounded-md border-gray-300"
+                                placeholder="Caption"
+                            />
+                            <input
+                                type="text"
+                                value=image.alt.clone()
+                                on:change=move |e| {
+                                    let alt = event_target_value(&e);
+                                    edit(&alt_key, &|image| image.alt = alt.clone());
+                                }
+                                class="text-xs rounded-md border-gray-300"
+                                placeholder="Alt text (describe the image)"
+                            />
+                        }
+                            .into_view()
+                    } else {
+                        view! { <figcaption class="text-xs text-gray-700">{caption}</figcaption> }
+                            .into_view()
+                    }}
+                    </figure>
+                }
+            }
+        </For>
+
+        // Lightbox
+        {move || {
+            lightbox
+                .get()
+                .and_then(|index| images.with(|images| images.get(index).cloned()).map(|image| (index, image)))
+                .map(|(index, image)| {
+                    let count = images.with(Vec::len);
+                    view! {
+                        <div
+                            class="flex fixed inset-0 z-50 flex-col justify-center items-center bg-black/90"
+                            role="dialog"
+                            aria-modal="true"
+                            on:click=move |_| lightbox.set(None)
+                        >
+                            <img
+                                src=asset_url(canister_id.get_untracked(), &image.key)
+                                alt=image.alt_text(index)
+                                class="object-contain max-w-[90vw] max-h-[80vh]"
+                                on:click=|e| e.stop_propagation()
+                            />
+                            <p class="mt-2 text-white">{image.caption.clone()}</p>
+                            <div class="flex gap-4 items-center mt-4 text-white" on:click=|e| e.stop_propagation()>
+                                <button type="button" on:click=move |_| step(-1) aria-label="Previous image">
+                                    "‹"
+                                </button>
+                                <span class="text-sm">{index + 1} " / " {count}</span>
+                                <button type="button" on:click=move |_| step(1) aria-label="Next image">
+                                    "›"
+                                </button>
+                                <button type="button" on:click=move |_| lightbox.set(None) aria-label="Close">
+                                    "✕"
+                                </button>
+                            </div>
+                        </div>
+                    }
+                })
+        }}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::move_item;
+
+    #[test]
+    fn moves_item_forward_and_back() {
+        let mut items = vec!['a', 'b', 'c', 'd'];
+        move_item(&mut items, 0, 2);
+        assert_eq!(items, ['b', 'c', 'a', 'd']);
+        move_item(&mut items, 3, 0);
+        assert_eq!(items, ['d', 'b', 'c', 'a']);
+    }
+
+    #[test]
+    fn ignores_out_of_range_and_same_position() {
+        let mut items = vec![1, 2, 3];
+        move_item(&mut items, 1, 1);
+        move_item(&mut items, 3, 0);
+        move_item(&mut items, 0, 3);
+        assert_eq!(items, [1, 2, 3]);
+    }
+}
@@ -0,0 +1,13 @@
+use crate::components::header::Header;
+use leptos::*;
+
+/// Shown in place of a page whose `?canister=` parameter is not a valid principal
+#[component]
+pub fn InvalidCanister(error: String) -> impl IntoView {
+    view! {
+        <Header />
+        <div class="container pt-24">
+            <p class="text-red-500">{error}</p>
+        </div>
+    }
+}
@@ -0,0 +1,94 @@
+use crate::outbound::upload_files::UploadField;
+use leptos::logging::log;
+use leptos::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, HtmlInputElement, Url};
+
+/// A file shown from an object URL while it is being uploaded
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocalPreview {
+    pub field: UploadField,
+    pub file_name: String,
+    pub content_type: String,
+    pub object_url: String,
+}
+
+impl LocalPreview {
+    /// Release the object URL once the canister-hosted copy replaces it
+    pub fn revoke(&self) {
+        if let Err(e) = Url::revoke_object_url(&self.object_url) {
+            log!("Failed to revoke {}: {:?}", self.object_url, e);
+        }
+    }
+}
+
+/// Object URL previews for the files selected in an `<input type="file">`
+pub fn local_previews(event: &Event, field: UploadField) -> Vec<LocalPreview> {
+    let Some(files) = event
+        .target()
+        .and_then(|t| t.dyn_into::<HtmlInputElement>().ok())
+        .and_then(|input| input.files())
+    else {
+        return Vec::new();
+    };
+
+    (0..files.length())
+        .filter_map(|i| files.get(i))
+        .filter_map(|file| match Url::create_object_url_with_blob(&file) {
+            Ok(object_url) => Some(LocalPreview {
+                field,
+                file_name: file.name(),
+                content_type: file.type_(),
+                object_url,
+            }),
+            Err(e) => {
+                log!("Failed to preview {}: {:?}", file.name(), e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// An image, or the first page of a PDF, rendered from its local copy under an
+/// "Uploading" overlay
+#[component]
+pub fn UploadingPreview(preview: LocalPreview) -> impl IntoView {
+    let LocalPreview {
+        file_name,
+        content_type,
+        object_url,
+        ..
+    } = preview;
+
+    let content = if content_type == "application/pdf" {
+        view! {
+            <object
+                data=format!("{}#page=1&toolbar=0&navpanes=0&view=FitH", object_url)
+                type="application/pdf"
+                class="w-full h-full pointer-events-none"
+            >
+                <span class="text-sm">{file_name.clone()}</span>
+            </object>
+        }
+        .into_view()
+    } else {
+        view! {
+            <img
+                src=object_url
+                alt=format!("Preview of {}", file_name)
+                class="object-contain w-full h-full rounded-md"
+            />
+        }
+        .into_view()
+    };
+
+    view! {
+        <div class="relative p-1 w-52 h-52 rounded-md border shrink-0">
+            {content}
+            <div class="flex absolute inset-0 flex-col justify-center items-center text-sm font-semibold text-white rounded-md bg-black/40">
+                <span>"Uploading..."</span>
+                <span class="px-2 text-xs font-normal truncate max-w-full">{file_name}</span>
+            </div>
+        </div>
+    }
+}
@@ -1,2 +1,13 @@
+pub mod admin_nav;
 pub mod counter_btn;
+pub mod edit_mode;
 pub mod header;
+pub mod image_gallery;
+pub mod invalid_canister;
+pub mod local_preview;
+pub mod not_authorized;
+pub mod permission_summary;
+pub mod proposed_batch;
+pub mod session_expiry;
+pub mod session_lock;
+pub mod verified_badge;
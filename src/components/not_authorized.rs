@@ -0,0 +1,40 @@
+use crate::outbound::authorization::NotAuthorized;
+use crate::utils::copy_to_clipboard;
+use leptos::*;
+
+/// Explains a missing `Commit` permission and what an admin runs to grant it
+#[component]
+pub fn NotAuthorizedNotice(error: NotAuthorized) -> impl IntoView {
+    let principal = error.principal.to_text();
+    let command = error.dfx_command();
+    let message = error.to_string();
+
+    view! {
+        <div class="flex flex-col gap-2 p-4 mt-2 text-sm rounded border border-red-300 bg-red-50">
+            <p class="font-medium text-red-700">{message}</p>
+            <div class="flex gap-2 items-center">
+                <code class="break-all">{principal.clone()}</code>
+                <button
+                    type="button"
+                    on:click=move |_| copy_to_clipboard(principal.clone())
+                    class="py-1 px-2 text-xs bg-white rounded border"
+                >
+                    "Copy principal"
+                </button>
+            </div>
+            <p>"Ask an admin of the asset canister to run:"</p>
+            <div class="flex gap-2 items-start">
+                <code class="p-2 font-mono text-xs break-all bg-white rounded border">
+                    {command.clone()}
+                </code>
+                <button
+                    type="button"
+                    on:click=move |_| copy_to_clipboard(command.clone())
+                    class="py-1 px-2 text-xs bg-white rounded border"
+                >
+                    "Copy command"
+                </button>
+            </div>
+        </div>
+    }
+}
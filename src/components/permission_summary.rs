@@ -0,0 +1,66 @@
+use crate::outbound::permissions::{all_permissions, list_permitted, permission_name};
+use crate::state::canisters::Canisters;
+use candid::Principal;
+use leptos::*;
+use std::rc::Rc;
+
+/// Read-only list of the principals holding each permission on an asset canister
+#[component]
+pub fn PermissionSummary(
+    #[prop(into)] canister_id: MaybeSignal<Principal>,
+    #[prop(into)] reload: MaybeSignal<i32>,
+) -> impl IntoView {
+    let canisters_signal = use_context::<RwSignal<Option<Rc<Canisters>>>>()
+        .expect("Canisters signal should be provided by AuthServiceProvider");
+
+    let lists = create_local_resource(
+        move || (canisters_signal.get(), canister_id.get(), reload.get()),
+        move |(canisters, canister_id, _)| async move {
+            let canisters = canisters?;
+            let mut lists = Vec::new();
+            for permission in all_permissions() {
+                let name = permission_name(&permission);
+                let principals = list_permitted(&canisters, canister_id, permission)
+                    .await
+                    .map_err(|e| e.to_string());
+                lists.push((name, principals));
+            }
+            Some(lists)
+        },
+    );
+
+    view! {
+        <Suspense fallback=|| view! { <p>"Loading permissions..."</p> }>
+            {move || {
+                lists
+                    .get()
+                    .flatten()
+                    .map(|lists| {
+                        lists
+                            .into_iter()
+                            .map(|(name, principals)| {
+                                let body = match principals {
+                                    Ok(principals) if principals.is_empty() => {
+                                        "No principals".into_view()
+                                    }
+                                    Ok(principals) => {
+                                        principals
+                                            .into_iter()
+                                            .map(|p| view! { <li><code>{p.to_text()}</code></li> })
+                                            .collect_view()
+                                    }
+                                    Err(e) => view! { <li class="text-red-500">{e}</li> }.into_view(),
+                                };
+                                view! {
+                                    <section class="mt-2 text-sm">
+                                        <h3 class="font-medium">{name}</h3>
+                                        <ul>{body}</ul>
+                                    </section>
+                                }
+                            })
+                            .collect_view()
+                    })
+            }}
+        </Suspense>
+    }
+}
@@ -0,0 +1,41 @@
+use crate::outbound::proposals::ProposedBatch;
+use crate::utils::{copy_to_clipboard, to_hex};
+use leptos::*;
+
+/// Batch id and evidence to put in the `commit_proposed_batch` governance proposal
+#[component]
+pub fn ProposedBatchSummary(proposal: ProposedBatch) -> impl IntoView {
+    let batch_id = proposal.batch_id.to_string();
+    let evidence = to_hex(&proposal.evidence);
+    let status_href = format!(
+        "/admin/proposals?canister={}&batch_id={}&evidence={}",
+        proposal.canister_id,
+        batch_id.replace('_', ""),
+        evidence
+    );
+
+    view! {
+        <div class="flex flex-col gap-2 p-4 text-sm rounded border border-yellow-300 bg-yellow-50">
+            <p class="font-medium">
+                "Batch " {batch_id.clone()} " is waiting for a governance proposal"
+            </p>
+            <ul class="list-disc list-inside">
+                {proposal.keys.into_iter().map(|key| view! { <li>{key}</li> }).collect_view()}
+            </ul>
+            <div class="flex gap-2 items-start">
+                <span>"Evidence:"</span>
+                <code class="font-mono text-xs break-all">{evidence.clone()}</code>
+                <button
+                    type="button"
+                    on:click=move |_| copy_to_clipboard(evidence.clone())
+                    class="py-1 px-2 text-xs bg-white rounded border"
+                >
+                    "Copy"
+                </button>
+            </div>
+            <a href=status_href class="text-blue-500 underline">
+                "Track proposal status"
+            </a>
+        </div>
+    }
+}
@@ -0,0 +1,51 @@
+use crate::state::auth::{use_auth, SessionStatus};
+use crate::state::auth_actions::create_login_action;
+use crate::utils::format_timestamp;
+use leptos::*;
+
+/// Warns before the delegation expires and renews it in the identity provider's
+/// window, leaving the page and any uploads in progress as they are
+#[component]
+pub fn SessionExpiryBanner() -> impl IntoView {
+    let auth = use_auth();
+    let relogin = create_login_action(auth);
+    let expires_at = move || {
+        auth.expires_at
+            .get()
+            .map(format_timestamp)
+            .unwrap_or_default()
+    };
+
+    move || {
+        let (class, message, button) = match auth.status.get() {
+            SessionStatus::Expiring => (
+                "border-amber-300 bg-amber-50 text-amber-800",
+                format!("Your session expires at {}.", expires_at()),
+                "Stay logged in",
+            ),
+            SessionStatus::Expired => (
+                "border-red-300 bg-red-50 text-red-700",
+                "Your session has expired. Uploads are paused until you log in again.".to_string(),
+                "Log in again",
+            ),
+            SessionStatus::Anonymous | SessionStatus::Active => return ().into_view(),
+        };
+        view! {
+            <div class=format!(
+                "flex fixed inset-x-0 top-20 z-40 gap-4 justify-center items-center py-2 text-sm border-b {}",
+                class,
+            )>
+                <span>{message}</span>
+                <button
+                    type="button"
+                    on:click=move |_| relogin.dispatch(auth.provider())
+                    disabled=move || relogin.pending().get()
+                    class="py-1 px-3 font-semibold text-white bg-blue-500 rounded hover:bg-blue-600"
+                >
+                    {button}
+                </button>
+            </div>
+        }
+            .into_view()
+    }
+}
@@ -0,0 +1,160 @@
+use crate::consts::auth::{IdleAction, IDLE_ACTION, IDLE_TIMEOUT, IDLE_WARNING};
+use crate::state::auth::use_auth;
+use leptos::*;
+use std::time::Duration;
+
+/// How often idle time is measured, also the resolution of the countdown
+const IDLE_TICK: Duration = Duration::from_secs(1);
+
+fn idle_action_text() -> &'static str {
+    match IDLE_ACTION {
+        IdleAction::Lock => "locked",
+        IdleAction::Logout => "logged out",
+    }
+}
+
+/// Applies `IDLE_ACTION` after `IDLE_TIMEOUT` without input, with a warning
+/// dialog during the last `IDLE_WARNING`, and covers the app while the session
+/// is locked
+#[component]
+pub fn SessionLock() -> impl IntoView {
+    let auth = use_auth();
+    let last_activity = store_value(js_sys::Date::now());
+    // Seconds left before the idle action, while the warning is shown
+    let (countdown, set_countdown) = create_signal(None::<u64>);
+    let (unlock_error, set_unlock_error) = create_signal(None::<String>);
+
+    // Once the warning is up only its button counts, so a stray mouse move
+    // doesn't hide it unread
+    let activity = move || {
+        if countdown.get_untracked().is_none() {
+            last_activity.set_value(js_sys::Date::now());
+        }
+    };
+    let listeners = [
+        window_event_listener(ev::pointerdown, move |_| activity()),
+        window_event_listener(ev::pointermove, move |_| activity()),
+        window_event_listener(ev::keydown, move |_| activity()),
+        window_event_listener(ev::wheel, move |_| activity()),
+        window_event_listener(ev::touchstart, move |_| activity()),
+    ];
+    on_cleanup(move || listeners.into_iter().for_each(|listener| listener.remove()));
+
+    let stay = move || {
+        last_activity.set_value(js_sys::Date::now());
+        set_countdown.set(None);
+    };
+    let idle_action = create_action(move |_: &()| async move {
+        set_countdown.set(None);
+        match IDLE_ACTION {
+            IdleAction::Lock => auth.lock(),
+            IdleAction::Logout => auth.logout().await,
+        }
+    });
+
+    if let Ok(ticker) = set_interval_with_handle(
+        move || {
+            let active = auth.is_authenticated.get_untracked() && !untrack(|| auth.is_locked());
+            if !active || untrack(|| auth.is_uploading()) {
+                stay();
+                return;
+            }
+            let idle =
+                Duration::from_millis((js_sys::Date::now() - last_activity.get_value()) as u64);
+            if idle >= IDLE_TIMEOUT {
+                if !idle_action.pending().get_untracked() {
+                    idle_action.dispatch(());
+                }
+            } else if idle >= IDLE_TIMEOUT - IDLE_WARNING {
+                set_countdown.set(Some((IDLE_TIMEOUT - idle).as_secs()));
+            } else {
+                set_countdown.set(None);
+            }
+        },
+        IDLE_TICK,
+    ) {
+        on_cleanup(move || ticker.clear());
+    }
+
+    let unlock = create_action(move |_: &()| async move {
+        set_unlock_error.set(auth.unlock().await.err());
+    });
+    let logout = create_action(move |_: &()| async move {
+        set_unlock_error.set(None);
+        auth.logout().await;
+    });
+
+    view! {
+        {move || {
+            countdown
+                .get()
+                .map(|seconds| {
+                    view! {
+                        <div
+                            class="flex fixed inset-0 z-[60] justify-center items-center bg-black/40"
+                            role="alertdialog"
+                            aria-modal="true"
+                        >
+                            <div class="flex flex-col gap-4 p-6 max-w-sm bg-white rounded shadow-lg">
+                                <p>
+                                    "You have been inactive for a while. Your session will be "
+                                    {idle_action_text()} " in " {seconds} " seconds."
+                                </p>
+                                <div class="flex gap-2 justify-end">
+                                    <button
+                                        type="button"
+                                        on:click=move |_| idle_action.dispatch(())
+                                        class="py-1 px-3 text-sm rounded border"
+                                    >
+                                        {match IDLE_ACTION {
+                                            IdleAction::Lock => "Lock now",
+                                            IdleAction::Logout => "Log out now",
+                                        }}
+                                    </button>
+                                    <button
+                                        type="button"
+                                        on:click=move |_| stay()
+                                        class="py-1 px-3 text-sm font-semibold text-white bg-blue-500 rounded hover:bg-blue-600"
+                                    >
+                                        "Stay signed in"
+                                    </button>
+                                </div>
+                            </div>
+                        </div>
+                    }
+                })
+        }}
+        <Show when=move || auth.is_locked()>
+            <div
+                class="flex fixed inset-0 flex-col gap-4 justify-center items-center bg-white z-[70]"
+                role="dialog"
+                aria-modal="true"
+            >
+                <h2 class="text-xl font-semibold">"Session locked"</h2>
+                <p class="text-sm text-gray-600">
+                    "Log in again as "
+                    <code>{move || auth.principal.get().map(|p| p.to_text()).unwrap_or_default()}</code>
+                    " to continue where you left off."
+                </p>
+                <div class="flex gap-2">
+                    <button
+                        type="button"
+                        on:click=move |_| logout.dispatch(())
+                        class="py-1 px-3 text-sm rounded border"
+                    >
+                        "Log out"
+                    </button>
+                    <button
+                        type="button"
+                        on:click=move |_| unlock.dispatch(())
+                        disabled=move || unlock.pending().get()
+                        class="py-1 px-3 text-sm font-semibold text-white bg-blue-500 rounded hover:bg-blue-600"
+                    >
+                        "Unlock"
+                    </button>
+                </div>
+                {move || unlock_error.get().map(|e| view! { <p class="text-sm text-red-500">{e}</p> })}
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,70 @@
+use crate::outbound::verify_asset::verify_certified_asset;
+use crate::state::canisters::{default_asset_canister, Canisters};
+use crate::utils::to_hex;
+use candid::Principal;
+use leptos::*;
+use std::rc::Rc;
+
+/// Shows whether an uploaded asset matches the hash certified by the asset canister
+#[component]
+pub fn VerifiedBadge(
+    #[prop(into)] asset_key: String,
+    /// Canister holding the asset, the shared one when not given
+    #[prop(default = None)]
+    canister_id: Option<Principal>,
+    #[prop(default = "identity")] encoding: &'static str,
+) -> impl IntoView {
+    let canisters_signal = use_context::<RwSignal<Option<Rc<Canisters>>>>()
+        .expect("Canisters signal should be provided by AuthServiceProvider");
+
+    let verification = create_local_resource(
+        move || canisters_signal.get(),
+        move |canisters| {
+            let asset_key = asset_key.clone();
+            async move {
+                let canisters = canisters?;
+                let canister_id = canister_id.unwrap_or_else(default_asset_canister);
+                Some(
+                    verify_certified_asset(&canisters, canister_id, &asset_key, encoding)
+                        .await
+                        .map_err(|e| e.to_string()),
+                )
+            }
+        },
+    );
+
+    view! {
+        {move || match verification.get().flatten() {
+            Some(Ok(verified)) => {
+                view! {
+                    <span
+                        class="py-0.5 px-2 text-xs font-semibold text-white bg-green-600 rounded-full"
+                        title=format!("sha256 {}", to_hex(&verified.sha256))
+                    >
+                        "verified"
+                    </span>
+                }
+                    .into_view()
+            }
+            Some(Err(e)) => {
+                view! {
+                    <span
+                        class="py-0.5 px-2 text-xs font-semibold text-white bg-red-500 rounded-full"
+                        title=e
+                    >
+                        "not verified"
+                    </span>
+                }
+                    .into_view()
+            }
+            None => {
+                view! {
+                    <span class="py-0.5 px-2 text-xs text-gray-600 bg-gray-200 rounded-full">
+                        "verifying..."
+                    </span>
+                }
+                    .into_view()
+            }
+        }}
+    }
+}
@@ -1 +1,5 @@
 pub const AGENT_URL: &str = "http://localhost:4943";
+/// Host asset canisters are served from by the local replica, prefixed with the canister id
+pub const ASSET_HOST: &str = "localhost:4943";
+/// Host the local replica serves Internet Identity from, prefixed with its canister id
+pub const II_HOST: &str = "localhost:4943";
@@ -6,6 +6,9 @@ pub mod local;
 
 pub mod remote;
 
+/// Asset canister that uploaded files are stored in
+pub const ASSET_CANISTER_ID: &str = "zcs7y-5iaaa-aaaam-adxfq-cai";
+
 pub mod auth {
     use web_time::Duration;
 
@@ -14,4 +17,59 @@ pub mod auth {
     /// Refresh expiry, 30 days
     pub const REFRESH_MAX_AGE: Duration = Duration::from_secs(60 * 60 * 24 * 30);
     pub const REFRESH_TOKEN_COOKIE: &str = "user-identity";
+    /// How long before the delegation expires the user is asked to log in again
+    pub const EXPIRY_WARNING: Duration = Duration::from_secs(60 * 10);
+    /// Interval at which the session's remaining lifetime is rechecked
+    pub const EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+    /// How long an upload paused on an expired session waits for it to be renewed
+    pub const RENEWAL_TIMEOUT: Duration = Duration::from_secs(60 * 15);
+
+    /// What happens to a session left idle for `IDLE_TIMEOUT`
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum IdleAction {
+        /// Cover the app until the same identity logs in again
+        Lock,
+        Logout,
+    }
+
+    /// Inactivity after which `IDLE_ACTION` is taken, uploads in progress count as activity
+    pub const IDLE_TIMEOUT: Duration = Duration::from_secs(60 * 15);
+    /// How long before `IDLE_TIMEOUT` the user is warned
+    pub const IDLE_WARNING: Duration = Duration::from_secs(60);
+    pub const IDLE_ACTION: IdleAction = IdleAction::Lock;
+    /// localStorage key of the principal whose session is locked, so a reload stays locked
+    pub const SESSION_LOCK_STORE: &str = "session-locked";
+
+    /// Mainnet Internet Identity login page
+    pub const II_URL: &str = "https://identity.ic0.app/#authorize";
+    /// NFID login page, which issues Internet Identity compatible delegations
+    pub const NFID_URL: &str = "https://nfid.one/authenticate/?applicationName=FuelDAO#authorize";
+    /// localStorage key of the provider last logged in with
+    pub const PROVIDER_STORE: &str = "identity-provider";
+    /// Fixed identity offered on a local replica for scripted testing, read from the
+    /// `DEV_IDENTITY_PEM` environment variable at build time. Ed25519 or secp256k1
+    /// PEM, never use a key that holds anything of value.
+    #[cfg(local_backend)]
+    pub const DEV_IDENTITY_PEM: Option<&str> = option_env!("DEV_IDENTITY_PEM");
+    /// Never compiled into builds for mainnet
+    #[cfg(not(local_backend))]
+    pub const DEV_IDENTITY_PEM: Option<&str> = None;
+}
+
+pub mod upload {
+    /// Largest chunk sent in a single `create_chunk` call, below the 2MiB ingress limit
+    pub const CHUNK_SIZE: usize = 1_900_000;
+    /// Cache lifetime of content-addressed images, 1 year
+    pub const IMMUTABLE_MAX_AGE: u64 = 60 * 60 * 24 * 365;
+    /// Cache lifetime of documents, which keep their file name as key, 1 hour
+    pub const DOCUMENT_MAX_AGE: u64 = 60 * 60;
+}
+
+pub mod draft {
+    use web_time::Duration;
+
+    /// localStorage key prefix of the collection form draft, followed by the principal
+    pub const DRAFT_STORE_PREFIX: &str = "car-collection-draft";
+    /// Quiet period after the last edit before the draft is saved
+    pub const AUTOSAVE_DEBOUNCE: Duration = Duration::from_millis(800);
 }
@@ -1 +1,3 @@
 pub const AGENT_URL: &str = "https://ic0.app";
+/// Host serving certified asset canister responses, prefixed with the canister id
+pub const ASSET_HOST: &str = "icp0.io";
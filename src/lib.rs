@@ -9,41 +9,33 @@ mod consts;
 mod pages;
 mod state;
 // mod stores;
-// mod utils;
-use std::cell::RefCell;
-use std::rc::Rc;
+mod utils;
 // Top-Level pages
+use crate::components::session_lock::SessionLock;
+use crate::pages::asset_detail::AssetDetail;
+use crate::pages::collection::CollectionDetail;
+use crate::pages::configuration::Configuration;
+use crate::pages::document::DocumentViewer;
 use crate::pages::home::Home;
 use crate::pages::not_found::NotFound;
-use crate::state::auth::AuthService;
-use crate::state::canisters::Canisters;
-use leptos::logging::log;
+use crate::pages::ownership::Ownership;
+use crate::pages::permissions::Permissions;
+use crate::pages::proposals::Proposals;
+use crate::pages::reviews::Reviews;
+use crate::state::auth::AuthState;
 
 /// An app router which renders the homepage and handles 404's
 
 #[component]
 fn AuthServiceProvider(children: Children) -> impl IntoView {
-    let auth_service = Rc::new(RefCell::new(
-        AuthService::new().expect("Failed to create AuthService"),
-    ));
-    provide_context(auth_service.clone());
-
     let canisters_signal = create_rw_signal(None);
     provide_context(canisters_signal);
 
-    spawn_local({
-        let auth_service = auth_service.clone();
-        async move {
-            match Canisters::new(auth_service).await {
-                Ok(canisters_instance) => {
-                    canisters_signal.set(Some(Rc::new(canisters_instance)));
-                }
-                Err(e) => log!("Failed to create Canisters: {:?}", e),
-            }
-        }
-    });
+    let auth = AuthState::new(canisters_signal);
+    provide_context(auth);
+
+    spawn_local(auth.restore());
 
-    // Provide AuthService as a context
     children()
 }
 
@@ -62,9 +54,18 @@ pub fn App() -> impl IntoView {
         <Meta charset="UTF-8" />
         <Meta name="viewport" content="width=device-width, initial-scale=1.0" />
         <AuthServiceProvider>
+            <SessionLock />
             <Router>
                 <Routes>
                     <Route path="/" view=Home />
+                    <Route path="/assets/*key" view=AssetDetail />
+                    <Route path="/collections/:id" view=CollectionDetail />
+                    <Route path="/documents/*key" view=DocumentViewer />
+                    <Route path="/admin/permissions" view=Permissions />
+                    <Route path="/admin/proposals" view=Proposals />
+                    <Route path="/admin/configuration" view=Configuration />
+                    <Route path="/admin/ownership" view=Ownership />
+                    <Route path="/admin/reviews" view=Reviews />
                     <Route path="/*" view=NotFound />
                 </Routes>
             </Router>
@@ -0,0 +1,143 @@
+use crate::canister::asset_proxy::{HeaderField, SetAssetPropertiesArguments};
+use crate::state::canisters::Canisters;
+use anyhow::{anyhow, bail, Error};
+use candid::Principal;
+use leptos::logging::log;
+
+/// Current properties of an asset as reported by `get_asset_properties`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AssetProperties {
+    pub max_age: Option<u64>,
+    pub headers: Option<Vec<(String, String)>>,
+    pub allow_raw_access: Option<bool>,
+    pub is_aliased: Option<bool>,
+}
+
+/// A change to one property, mapping onto the `opt opt` fields of
+/// `SetAssetPropertiesArguments`: `null` leaves the value alone, `opt null`
+/// clears it and `opt opt v` sets it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum PropertyEdit<T> {
+    #[default]
+    Unchanged,
+    Clear,
+    Set(T),
+}
+
+impl<T> PropertyEdit<T> {
+    pub fn into_arg(self) -> Option<Option<T>> {
+        match self {
+            PropertyEdit::Unchanged => None,
+            PropertyEdit::Clear => Some(None),
+            PropertyEdit::Set(value) => Some(Some(value)),
+        }
+    }
+
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, PropertyEdit::Unchanged)
+    }
+}
+
+/// Edits applied to an asset through `set_asset_properties`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AssetPropertiesEdit {
+    pub max_age: PropertyEdit<u64>,
+    pub headers: PropertyEdit<Vec<(String, String)>>,
+    pub allow_raw_access: PropertyEdit<bool>,
+    pub is_aliased: PropertyEdit<bool>,
+}
+
+impl AssetPropertiesEdit {
+    pub fn is_empty(&self) -> bool {
+        self.max_age.is_unchanged()
+            && self.headers.is_unchanged()
+            && self.allow_raw_access.is_unchanged()
+            && self.is_aliased.is_unchanged()
+    }
+
+    pub fn into_arguments(self, key: String) -> SetAssetPropertiesArguments {
+        SetAssetPropertiesArguments {
+            key,
+            max_age: self.max_age.into_arg(),
+            headers: self
+                .headers
+                .into_arg()
+                .map(|headers| headers.map(to_header_fields)),
+            allow_raw_access: self.allow_raw_access.into_arg(),
+            is_aliased: self.is_aliased.into_arg(),
+        }
+    }
+}
+
+/// Parse `Name: value` lines into header pairs, skipping blank lines.
+pub fn parse_headers(text: &str) -> Result<Vec<(String, String)>, Error> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| match line.split_once(':') {
+            Some((name, value)) if !name.trim().is_empty() => {
+                Ok((name.trim().to_string(), value.trim().to_string()))
+            }
+            _ => Err(anyhow!("Invalid header line: {}", line)),
+        })
+        .collect()
+}
+
+/// Render header pairs back into the `Name: value` format accepted by `parse_headers`.
+pub fn format_headers(headers: &[(String, String)]) -> String {
+    headers
+        .iter()
+        .map(|(name, value)| format!("{}: {}", name, value))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn to_header_fields(headers: Vec<(String, String)>) -> Vec<HeaderField> {
+    headers
+        .into_iter()
+        .map(|(name, value)| HeaderField(name, value))
+        .collect()
+}
+
+pub async fn get_asset_properties(
+    canisters: &Canisters,
+    canister_id: Principal,
+    key: &str,
+) -> Result<AssetProperties, Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    let properties = asset_proxy
+        .get_asset_properties(key.to_string())
+        .await
+        .map_err(|e| anyhow!("Failed to get properties of {}: {:?}", key, e))?;
+
+    Ok(AssetProperties {
+        max_age: properties.max_age,
+        headers: properties.headers.map(|headers| {
+            headers
+                .into_iter()
+                .map(|HeaderField(name, value)| (name, value))
+                .collect()
+        }),
+        allow_raw_access: properties.allow_raw_access,
+        is_aliased: properties.is_aliased,
+    })
+}
+
+pub async fn set_asset_properties(
+    canisters: &Canisters,
+    canister_id: Principal,
+    key: &str,
+    edit: AssetPropertiesEdit,
+) -> Result<(), Error> {
+    if edit.is_empty() {
+        bail!("No property changes to save for {}", key);
+    }
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    asset_proxy
+        .set_asset_properties(edit.into_arguments(key.to_string()))
+        .await
+        .map_err(|e| anyhow!("Failed to set properties of {}: {:?}", key, e))?;
+    log!("Updated properties of {}", key);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use crate::canister::provision::{Asset, AssetEncoding, Result_};
+use crate::outbound::batch_upload::BatchAsset;
+use crate::outbound::canister_error::canister_error_message;
+use crate::state::canisters::Canisters;
+use anyhow::{anyhow, bail, Error};
+use candid::Principal;
+use leptos::logging::log;
+use serde_bytes::ByteBuf;
+use web_time::{SystemTime, UNIX_EPOCH};
+
+/// Id of the record for `key`, namespaced by canister since every asset canister
+/// has its own key space
+pub fn record_id(canister_id: Principal, key: &str) -> String {
+    format!("{}/{}", canister_id, key.trim_start_matches('/'))
+}
+
+/// Metadata of an uploaded asset as recorded by the provision canister.
+///
+/// Only the "identity" encoding is uploaded, and the content itself stays in the
+/// asset canister, so `content_chunks` is left empty.
+pub fn asset_record(canister_id: Principal, owner: Principal, asset: &BatchAsset) -> Asset {
+    let modified = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or_default();
+    let options = &asset.options;
+
+    Asset {
+        id: record_id(canister_id, &asset.key),
+        encodings: vec![(
+            "identity".to_string(),
+            AssetEncoding {
+                modified,
+                sha256: ByteBuf::from(asset.sha256.clone()),
+                certified: false,
+                content_chunks: Vec::new(),
+                total_length: asset.content.len() as u64,
+            },
+        )],
+        owner,
+        content_type: asset.content_type.clone(),
+        headers: (!options.headers.is_empty()).then(|| options.headers.clone()),
+        is_aliased: options.enable_aliasing,
+        allow_raw_access: options.allow_raw_access,
+        max_age: options.max_age,
+    }
+}
+
+/// Record an uploaded asset with `add_asset`
+pub async fn register_asset(canisters: &Canisters, asset: Asset) -> Result<(), Error> {
+    let provision = canisters.provision_canister().await;
+    let id = asset.id.clone();
+    match provision
+        .add_asset(asset)
+        .await
+        .map_err(|e| anyhow!("add_asset failed: {}", canister_error_message(&e)))?
+    {
+        Result_::Ok => {
+            log!("Registered asset {}", id);
+            Ok(())
+        }
+        Result_::Err(e) => bail!("Failed to register asset {}: {}", id, e),
+    }
+}
+
+/// Metadata recorded for `key` in `canister_id`, `None` if it was never registered
+pub async fn get_registered_asset(
+    canisters: &Canisters,
+    canister_id: Principal,
+    key: &str,
+) -> Result<Option<Asset>, Error> {
+    let provision = canisters.provision_canister().await;
+    provision
+        .get_asset(record_id(canister_id, key))
+        .await
+        .map_err(|e| anyhow!("get_asset failed: {}", canister_error_message(&e)))
+}
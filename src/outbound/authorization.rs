@@ -0,0 +1,96 @@
+use crate::canister::asset_proxy::Permission;
+use crate::outbound::permissions::{list_authorized, list_permitted, permission_name};
+use crate::state::canisters::Canisters;
+use anyhow::{anyhow, Error};
+use candid::Principal;
+use dotenv_codegen::dotenv;
+use leptos::logging::log;
+use std::fmt;
+
+/// The logged-in principal lacks the permission an upload needs on the asset canister
+#[derive(Clone, Debug, PartialEq)]
+pub struct NotAuthorized {
+    pub principal: Principal,
+    pub canister_id: Principal,
+    pub permission: &'static str,
+}
+
+impl NotAuthorized {
+    /// dfx command an admin runs to let the principal upload
+    pub fn dfx_command(&self) -> String {
+        let network = match dotenv!("BACKEND") {
+            "LOCAL" => "local",
+            _ => "ic",
+        };
+        format!(
+            "dfx canister --network {} call {} grant_permission '(record {{ to_principal = principal \"{}\"; permission = variant {{ {} }} }})'",
+            network, self.canister_id, self.principal, self.permission
+        )
+    }
+}
+
+impl fmt::Display for NotAuthorized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Your principal {} is not authorized on canister {} (missing {} permission)",
+            self.principal, self.canister_id, self.permission
+        )
+    }
+}
+
+impl std::error::Error for NotAuthorized {}
+
+/// Whether a canister reject message means the caller lacks a permission
+pub fn is_permission_reject(message: &str) -> bool {
+    (message.contains("does not have") && message.contains("permission"))
+        || message.contains("not authorized")
+}
+
+/// Check whether the agent's principal is listed as allowed to run an upload.
+///
+/// Direct uploads need `Commit`, granted through `grant_permission` or the legacy
+/// `authorize`. Proposed uploads need `Prepare`, which `Commit` also implies.
+/// Controllers may upload without appearing in any list, so `false` only means the
+/// check was inconclusive; the upload is tried anyway and a reject is matched with
+/// `is_permission_reject`.
+pub async fn check_upload_authorization(
+    canisters: &Canisters,
+    canister_id: Principal,
+    permission: Permission,
+) -> Result<bool, Error> {
+    let principal = canisters
+        .agent
+        .get_principal()
+        .map_err(|e| anyhow!("Failed to get principal: {}", e))?;
+
+    if !matches!(permission, Permission::Commit)
+        && is_listed(
+            list_permitted(canisters, canister_id, permission).await,
+            principal,
+        )
+    {
+        return Ok(true);
+    }
+    if is_listed(
+        list_permitted(canisters, canister_id, Permission::Commit).await,
+        principal,
+    ) {
+        return Ok(true);
+    }
+    Ok(is_listed(
+        list_authorized(canisters, canister_id).await,
+        principal,
+    ))
+}
+
+/// A list that could not be read counts as not listing the principal
+fn is_listed(list: Result<Vec<Principal>, Error>, principal: Principal) -> bool {
+    match list {
+        Ok(principals) => principals.contains(&principal),
+        Err(e) => {
+            log!("Could not read permission list: {:?}", e);
+            false
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::canister::asset_proxy::{
+    BatchId, BatchOperationKind, CommitBatchArguments, CreateAssetArguments, CreateBatchArg,
+    CreateChunkArg, DeleteAssetArguments, HeaderField, SetAssetContentArguments,
+};
+use crate::consts::upload::CHUNK_SIZE;
+use crate::outbound::canister_error::{call_error, canister_error_message};
+use crate::state::canisters::Canisters;
+use anyhow::{anyhow, Error};
+use candid::Principal;
+use leptos::logging::log;
+use serde_bytes::ByteBuf;
+
+/// Properties written in the `CreateAsset` operation of a batch
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AssetOptions {
+    pub max_age: Option<u64>,
+    pub headers: Vec<(String, String)>,
+    pub enable_aliasing: Option<bool>,
+    pub allow_raw_access: Option<bool>,
+}
+
+/// A single asset to be created in a batch
+#[derive(Clone, Debug)]
+pub struct BatchAsset {
+    pub key: String,
+    pub content_type: String,
+    pub content: Vec<u8>,
+    pub sha256: Vec<u8>,
+    pub options: AssetOptions,
+}
+
+/// Create a batch, upload the chunks of every asset and build the operations that
+/// create them. The returned arguments are ready for `commit_batch`.
+pub async fn stage_batch(
+    canisters: &Canisters,
+    canister_id: Principal,
+    assets: Vec<BatchAsset>,
+) -> Result<CommitBatchArguments, Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+
+    let batch_id: BatchId = asset_proxy
+        .create_batch(CreateBatchArg {})
+        .await
+        .map_err(|e| call_error("Failed to create batch".to_string(), e))?
+        .batch_id;
+    log!("Created batch {}", batch_id);
+
+    let mut operations = Vec::new();
+    for asset in assets {
+        let mut chunk_ids = Vec::new();
+        for chunk in asset.content.chunks(CHUNK_SIZE) {
+            let created = asset_proxy
+                .create_chunk(CreateChunkArg {
+                    batch_id: batch_id.clone(),
+                    content: ByteBuf::from(chunk.to_vec()),
+                })
+                .await
+                .map_err(|e| call_error(format!("Failed to upload chunk of {}", asset.key), e))?;
+            chunk_ids.push(created.chunk_id);
+        }
+        log!("Uploaded {} chunk(s) for {}", chunk_ids.len(), asset.key);
+
+        operations.push(BatchOperationKind::CreateAsset(CreateAssetArguments {
+            key: asset.key.clone(),
+            content_type: asset.content_type,
+            max_age: asset.options.max_age,
+            headers: if asset.options.headers.is_empty() {
+                None
+            } else {
+                Some(
+                    asset
+                        .options
+                        .headers
+                        .into_iter()
+                        .map(|(name, value)| HeaderField(name, value))
+                        .collect(),
+                )
+            },
+            enable_aliasing: asset.options.enable_aliasing,
+            allow_raw_access: asset.options.allow_raw_access,
+        }));
+        operations.push(BatchOperationKind::SetAssetContent(
+            SetAssetContentArguments {
+                key: asset.key,
+                content_encoding: "identity".to_string(),
+                chunk_ids,
+                sha256: Some(ByteBuf::from(asset.sha256)),
+            },
+        ));
+    }
+
+    Ok(CommitBatchArguments {
+        batch_id,
+        operations,
+    })
+}
+
+/// Stage `assets` and commit them in one batch.
+pub async fn upload_batch(
+    canisters: &Canisters,
+    canister_id: Principal,
+    assets: Vec<BatchAsset>,
+) -> Result<(), Error> {
+    let arguments = stage_batch(canisters, canister_id, assets).await?;
+    let batch_id = arguments.batch_id.clone();
+
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    asset_proxy
+        .commit_batch(arguments)
+        .await
+        .map_err(|e| call_error(format!("Failed to commit batch {}", batch_id), e))?;
+    log!("Committed batch {}", batch_id);
+
+    Ok(())
+}
+
+/// Delete `keys` from the asset canister in a single batch
+pub async fn delete_assets(
+    canisters: &Canisters,
+    canister_id: Principal,
+    keys: Vec<String>,
+) -> Result<(), Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+
+    let batch_id: BatchId = asset_proxy
+        .create_batch(CreateBatchArg {})
+        .await
+        .map_err(|e| anyhow!("Failed to create batch: {}", canister_error_message(&e)))?
+        .batch_id;
+    let count = keys.len();
+    let operations = keys
+        .into_iter()
+        .map(|key| BatchOperationKind::DeleteAsset(DeleteAssetArguments { key }))
+        .collect();
+
+    asset_proxy
+        .commit_batch(CommitBatchArguments {
+            batch_id: batch_id.clone(),
+            operations,
+        })
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "Failed to commit batch {}: {}",
+                batch_id,
+                canister_error_message(&e)
+            )
+        })?;
+    log!("Deleted {} asset(s) in batch {}", count, batch_id);
+
+    Ok(())
+}
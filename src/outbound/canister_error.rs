@@ -0,0 +1,103 @@
+use anyhow::Error;
+use ic_agent::AgentError;
+
+/// Human readable form of an agent error, preferring the canister's reject message
+pub fn canister_error_message(err: &AgentError) -> String {
+    match err {
+        AgentError::CertifiedReject(reject) | AgentError::UncertifiedReject(reject) => {
+            format!(
+                "{} (reject code {:?})",
+                reject.reject_message, reject.reject_code
+            )
+        }
+        other => other.to_string(),
+    }
+}
+
+/// Error for a failed call described by `context`, keeping the agent error so
+/// callers can still inspect it with `downcast_ref::<AgentError>()`
+pub fn call_error(context: String, err: AgentError) -> Error {
+    let message = format!("{}: {}", context, canister_error_message(&err));
+    Error::new(err).context(message)
+}
+
+/// Whether a call was rejected because the caller's delegation has expired.
+/// The replica reports this as a reject while the boundary node answers the
+/// request itself with HTTP 400.
+pub fn is_expired_delegation(err: &AgentError) -> bool {
+    match err {
+        AgentError::CertifiedReject(reject) | AgentError::UncertifiedReject(reject) => {
+            mentions_expired_delegation(&reject.reject_message)
+        }
+        AgentError::HttpError(payload) => {
+            payload.status == 400
+                && mentions_expired_delegation(&String::from_utf8_lossy(&payload.content))
+        }
+        _ => false,
+    }
+}
+
+fn mentions_expired_delegation(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("delegation has expired") || message.contains("invalid delegation expiry")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ic_agent::agent::{RejectCode, RejectResponse};
+    use ic_agent::agent_error::HttpErrorPayload;
+
+    fn reject(message: &str) -> RejectResponse {
+        RejectResponse {
+            reject_code: RejectCode::CanisterReject,
+            reject_message: message.to_string(),
+            error_code: None,
+        }
+    }
+
+    fn http_error(status: u16, body: &str) -> AgentError {
+        AgentError::HttpError(HttpErrorPayload {
+            status,
+            content_type: Some("text/plain".to_string()),
+            content: body.as_bytes().to_vec(),
+        })
+    }
+
+    #[test]
+    fn matches_expired_delegation_rejects() {
+        let message = "Invalid delegation: delegation has expired";
+        assert!(is_expired_delegation(&AgentError::CertifiedReject(reject(
+            message
+        ))));
+        assert!(is_expired_delegation(&AgentError::UncertifiedReject(
+            reject(message)
+        )));
+        assert!(!is_expired_delegation(&AgentError::CertifiedReject(
+            reject("Caller does not have Commit permission")
+        )));
+    }
+
+    #[test]
+    fn matches_boundary_node_expiry_responses() {
+        assert!(is_expired_delegation(&http_error(
+            400,
+            "Invalid delegation expiry: Specified ingress_expiry not within expected range"
+        )));
+        assert!(!is_expired_delegation(&http_error(
+            400,
+            "Invalid content type"
+        )));
+        assert!(!is_expired_delegation(&http_error(
+            503,
+            "Invalid delegation expiry"
+        )));
+    }
+
+    #[test]
+    fn ignores_other_errors() {
+        assert!(!is_expired_delegation(
+            &AgentError::TimeoutWaitingForResponse()
+        ));
+    }
+}
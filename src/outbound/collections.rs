@@ -0,0 +1,152 @@
+use crate::canister::provision::{
+    self, CarCollectionSubmission, GalleryUpdate, Result_, ReviewStatus, SubmitResult,
+};
+use crate::outbound::canister_error::canister_error_message;
+use crate::state::canisters::Canisters;
+use crate::state::collection::{
+    CarCollection, CollectionDocument, CollectionImage, DocumentCategory,
+};
+use anyhow::{anyhow, bail, Error};
+use leptos::logging::log;
+
+/// Submit the collection for review with `add_car_collection`, owned by the caller.
+///
+/// New collections (id 0) get their id from the canister, which is returned.
+pub async fn submit_collection(
+    canisters: &Canisters,
+    collection: CarCollection,
+) -> Result<u64, Error> {
+    let owner = canisters
+        .agent
+        .get_principal()
+        .map_err(|e| anyhow!("Failed to get principal: {}", e))?;
+    let provision = canisters.provision_canister().await;
+    let submission = CarCollectionSubmission {
+        id: (collection.id != 0).then_some(collection.id),
+        model: collection.model,
+        documents: collection.documents.into_iter().map(Into::into).collect(),
+        owner,
+        logo: collection.logo,
+        name: collection.name,
+        images: collection.images.into_iter().map(Into::into).collect(),
+        asset_canister: collection.asset_canister,
+    };
+
+    match provision
+        .add_car_collection(submission)
+        .await
+        .map_err(|e| anyhow!("add_car_collection failed: {}", canister_error_message(&e)))?
+    {
+        SubmitResult::Ok(id) => {
+            log!("Submitted collection {}", id);
+            Ok(id)
+        }
+        SubmitResult::Err(e) => bail!("Failed to submit collection: {}", e),
+    }
+}
+
+impl From<CollectionImage> for provision::CollectionImage {
+    fn from(image: CollectionImage) -> Self {
+        Self {
+            key: image.key,
+            caption: image.caption,
+            alt: image.alt,
+        }
+    }
+}
+
+impl From<provision::CollectionImage> for CollectionImage {
+    fn from(image: provision::CollectionImage) -> Self {
+        Self {
+            key: image.key,
+            caption: image.caption,
+            alt: image.alt,
+        }
+    }
+}
+
+impl From<CollectionDocument> for provision::CollectionDocument {
+    fn from(document: CollectionDocument) -> Self {
+        Self {
+            key: document.key,
+            category: match document.category {
+                DocumentCategory::Registration => provision::DocumentCategory::Registration,
+                DocumentCategory::Insurance => provision::DocumentCategory::Insurance,
+                DocumentCategory::InspectionReport => provision::DocumentCategory::InspectionReport,
+                DocumentCategory::Invoice => provision::DocumentCategory::Invoice,
+                DocumentCategory::Other => provision::DocumentCategory::Other,
+            },
+            issuer: document.issuer,
+            expires_at: document.expires_at,
+        }
+    }
+}
+
+impl From<provision::CollectionDocument> for CollectionDocument {
+    fn from(document: provision::CollectionDocument) -> Self {
+        Self {
+            key: document.key,
+            category: match document.category {
+                provision::DocumentCategory::Registration => DocumentCategory::Registration,
+                provision::DocumentCategory::Insurance => DocumentCategory::Insurance,
+                provision::DocumentCategory::InspectionReport => DocumentCategory::InspectionReport,
+                provision::DocumentCategory::Invoice => DocumentCategory::Invoice,
+                provision::DocumentCategory::Other => DocumentCategory::Other,
+            },
+            issuer: document.issuer,
+            expires_at: document.expires_at,
+        }
+    }
+}
+
+/// A stored collection, `None` if no collection has this id
+pub async fn get_collection(
+    canisters: &Canisters,
+    id: u64,
+) -> Result<Option<provision::CarCollection>, Error> {
+    let provision = canisters.provision_canister().await;
+    provision
+        .get_car_collection(id)
+        .await
+        .map_err(|e| anyhow!("get_car_collection failed: {}", canister_error_message(&e)))
+}
+
+/// Store the images, with their order and text, and cover of a submitted collection.
+/// Only its owner may.
+pub async fn save_gallery(
+    canisters: &Canisters,
+    id: u64,
+    images: Vec<CollectionImage>,
+    logo: String,
+) -> Result<(), Error> {
+    let provision = canisters.provision_canister().await;
+    match provision
+        .update_collection_gallery(
+            id,
+            GalleryUpdate {
+                images: images.into_iter().map(Into::into).collect(),
+                logo,
+            },
+        )
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "update_collection_gallery failed: {}",
+                canister_error_message(&e)
+            )
+        })? {
+        Result_::Ok => {
+            log!("Saved gallery of collection {}", id);
+            Ok(())
+        }
+        Result_::Err(e) => bail!("Failed to save gallery of collection {}: {}", id, e),
+    }
+}
+
+pub fn status_text(status: &ReviewStatus) -> String {
+    match status {
+        ReviewStatus::Pending => "Pending review".to_string(),
+        ReviewStatus::Approved => "Approved".to_string(),
+        ReviewStatus::Rejected(reason) => format!("Rejected: {}", reason),
+    }
+}
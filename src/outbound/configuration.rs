@@ -0,0 +1,238 @@
+use crate::canister::asset_proxy::{ConfigureArguments, ValidationResult};
+use crate::consts::upload::CHUNK_SIZE;
+use crate::outbound::asset_properties::PropertyEdit;
+use crate::outbound::batch_upload::BatchAsset;
+use crate::outbound::canister_error::canister_error_message;
+use crate::state::canisters::Canisters;
+use anyhow::{anyhow, bail, Error};
+use candid::Principal;
+use leptos::logging::log;
+
+/// Limits reported by `get_configuration`, `None` meaning unlimited
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UploadLimits {
+    pub max_batches: Option<u64>,
+    pub max_chunks: Option<u64>,
+    pub max_bytes: Option<u64>,
+}
+
+/// Changes applied through `configure`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConfigurationEdit {
+    pub max_batches: PropertyEdit<u64>,
+    pub max_chunks: PropertyEdit<u64>,
+    pub max_bytes: PropertyEdit<u64>,
+}
+
+impl ConfigurationEdit {
+    pub fn into_arguments(self) -> ConfigureArguments {
+        ConfigureArguments {
+            max_batches: self.max_batches.into_arg(),
+            max_chunks: self.max_chunks.into_arg(),
+            max_bytes: self.max_bytes.into_arg(),
+        }
+    }
+}
+
+pub async fn get_configuration(
+    canisters: &Canisters,
+    canister_id: Principal,
+) -> Result<UploadLimits, Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    let configuration = asset_proxy
+        .get_configuration()
+        .await
+        .map_err(|e| anyhow!("get_configuration failed: {}", canister_error_message(&e)))?;
+
+    Ok(UploadLimits {
+        max_batches: configuration.max_batches,
+        max_chunks: configuration.max_chunks,
+        max_bytes: configuration.max_bytes,
+    })
+}
+
+/// Check an edit the way a governance proposal would before it is executed
+pub async fn validate_configure(
+    canisters: &Canisters,
+    canister_id: Principal,
+    edit: ConfigurationEdit,
+) -> Result<Result<String, String>, Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    let result = asset_proxy
+        .validate_configure(edit.into_arguments())
+        .await
+        .map_err(|e| anyhow!("validate_configure failed: {}", canister_error_message(&e)))?;
+
+    Ok(match result {
+        ValidationResult::Ok(message) => Ok(message),
+        ValidationResult::Err(message) => Err(message),
+    })
+}
+
+pub async fn configure(
+    canisters: &Canisters,
+    canister_id: Principal,
+    edit: ConfigurationEdit,
+) -> Result<(), Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    asset_proxy
+        .configure(edit.into_arguments())
+        .await
+        .map_err(|e| anyhow!("configure failed: {}", canister_error_message(&e)))?;
+    log!("Updated configuration of {}", canister_id);
+
+    Ok(())
+}
+
+/// Number of `create_chunk` calls needed for an asset
+pub fn chunk_count(asset: &BatchAsset) -> u64 {
+    asset.content.len().div_ceil(CHUNK_SIZE).max(1) as u64
+}
+
+impl UploadLimits {
+    /// Why a single asset can never fit in a batch under these limits, if it can't
+    pub fn rejects(&self, asset: &BatchAsset) -> Option<String> {
+        let chunks = chunk_count(asset);
+        let bytes = asset.content.len() as u64;
+        match (self.max_chunks, self.max_bytes) {
+            (Some(max_chunks), _) if chunks > max_chunks => Some(format!(
+                "{} needs {} chunks but the canister allows {}",
+                asset.key, chunks, max_chunks
+            )),
+            (_, Some(max_bytes)) if bytes > max_bytes => Some(format!(
+                "{} is {} bytes but the canister allows {}",
+                asset.key, bytes, max_bytes
+            )),
+            _ => None,
+        }
+    }
+
+    /// Split `assets` into batches that each stay within `max_chunks` and `max_bytes`.
+    /// Fails if any single asset exceeds them or more batches than `max_batches` are needed.
+    pub fn plan_batches(&self, assets: Vec<BatchAsset>) -> Result<Vec<Vec<BatchAsset>>, Error> {
+        if let Some(reason) = assets.iter().find_map(|asset| self.rejects(asset)) {
+            bail!(reason);
+        }
+
+        let mut batches: Vec<Vec<BatchAsset>> = Vec::new();
+        let (mut chunks, mut bytes) = (0u64, 0u64);
+        for asset in assets {
+            let asset_chunks = chunk_count(&asset);
+            let asset_bytes = asset.content.len() as u64;
+            let fits = self
+                .max_chunks
+                .map_or(true, |max| chunks + asset_chunks <= max)
+                && self
+                    .max_bytes
+                    .map_or(true, |max| bytes + asset_bytes <= max);
+            match batches.last_mut() {
+                Some(batch) if fits => batch.push(asset),
+                _ => {
+                    batches.push(vec![asset]);
+                    chunks = 0;
+                    bytes = 0;
+                }
+            }
+            chunks += asset_chunks;
+            bytes += asset_bytes;
+        }
+
+        if let Some(max_batches) = self.max_batches {
+            if batches.len() as u64 > max_batches {
+                bail!(
+                    "Upload needs {} batches but the canister allows {} at a time",
+                    batches.len(),
+                    max_batches
+                );
+            }
+        }
+
+        Ok(batches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::outbound::batch_upload::AssetOptions;
+
+    fn asset(key: &str, len: usize) -> BatchAsset {
+        BatchAsset {
+            key: key.to_string(),
+            content_type: "application/octet-stream".to_string(),
+            content: vec![0; len],
+            sha256: Vec::new(),
+            options: AssetOptions::default(),
+        }
+    }
+
+    fn keys(batches: &[Vec<BatchAsset>]) -> Vec<Vec<&str>> {
+        batches
+            .iter()
+            .map(|batch| batch.iter().map(|asset| asset.key.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn plans_nothing_for_no_assets() {
+        let limits = UploadLimits {
+            max_batches: Some(0),
+            max_chunks: Some(1),
+            max_bytes: Some(1),
+        };
+        assert!(limits.plan_batches(Vec::new()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn fills_a_batch_up_to_the_exact_limit() {
+        let limits = UploadLimits {
+            max_batches: Some(2),
+            max_chunks: Some(2),
+            max_bytes: Some(100),
+        };
+        let assets = vec![asset("/a", 60), asset("/b", 40), asset("/c", 100)];
+        assert!(assets.iter().all(|asset| limits.rejects(asset).is_none()));
+
+        let batches = limits.plan_batches(assets).unwrap();
+        assert_eq!(keys(&batches), vec![vec!["/a", "/b"], vec!["/c"]]);
+    }
+
+    #[test]
+    fn allows_exactly_max_chunks() {
+        let limits = UploadLimits {
+            max_chunks: Some(2),
+            ..UploadLimits::default()
+        };
+        let two_chunks = asset("/two", CHUNK_SIZE * 2);
+        assert_eq!(chunk_count(&two_chunks), 2);
+        assert_eq!(limits.rejects(&two_chunks), None);
+    }
+
+    #[test]
+    fn rejects_an_asset_larger_than_the_chunk_limit() {
+        let limits = UploadLimits {
+            max_chunks: Some(1),
+            ..UploadLimits::default()
+        };
+        let large = asset("/large", CHUNK_SIZE + 1);
+        assert_eq!(
+            limits.rejects(&large),
+            Some("/large needs 2 chunks but the canister allows 1".to_string())
+        );
+        assert!(limits
+            .plan_batches(vec![asset("/small", 10), large])
+            .is_err());
+    }
+
+    #[test]
+    fn fails_when_more_batches_are_needed_than_allowed() {
+        let limits = UploadLimits {
+            max_batches: Some(1),
+            max_chunks: None,
+            max_bytes: Some(100),
+        };
+        assert!(limits
+            .plan_batches(vec![asset("/a", 100), asset("/b", 1)])
+            .is_err());
+    }
+}
@@ -0,0 +1,72 @@
+use crate::canister::asset_proxy::{GetArg, GetChunkArg};
+use crate::state::canisters::Canisters;
+use anyhow::{anyhow, bail, Error};
+use candid::{Nat, Principal};
+use leptos::logging::log;
+
+/// Content of a single asset encoding, reassembled from `get` and `get_chunk`
+#[derive(Clone, Debug)]
+pub struct FetchedAsset {
+    pub key: String,
+    pub content: Vec<u8>,
+    pub content_type: String,
+    pub content_encoding: String,
+    pub sha256: Option<Vec<u8>>,
+}
+
+/// Download every chunk of `key` in the requested `encoding` from the asset canister.
+pub async fn fetch_asset(
+    canisters: &Canisters,
+    canister_id: Principal,
+    key: &str,
+    encoding: &str,
+) -> Result<FetchedAsset, Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+
+    let first = asset_proxy
+        .get(GetArg {
+            key: key.to_string(),
+            accept_encodings: vec![encoding.to_string()],
+        })
+        .await
+        .map_err(|e| anyhow!("Failed to get asset {}: {:?}", key, e))?;
+    let total_length = u64::try_from(&first.total_length.0)?;
+
+    let mut content = first.content.into_vec();
+    let mut index: u64 = 1;
+    while (content.len() as u64) < total_length {
+        let chunk = asset_proxy
+            .get_chunk(GetChunkArg {
+                key: key.to_string(),
+                content_encoding: first.content_encoding.clone(),
+                index: Nat::from(index),
+                sha256: first.sha256.clone(),
+            })
+            .await
+            .map_err(|e| anyhow!("Failed to get chunk {} of {}: {:?}", index, key, e))?;
+        if chunk.content.is_empty() {
+            bail!(
+                "Asset {} ended after {} of {} bytes",
+                key,
+                content.len(),
+                total_length
+            );
+        }
+        content.extend_from_slice(&chunk.content);
+        index += 1;
+    }
+    log!(
+        "Fetched {} bytes of {} in {} chunk(s)",
+        content.len(),
+        key,
+        index
+    );
+
+    Ok(FetchedAsset {
+        key: key.to_string(),
+        content,
+        content_type: first.content_type,
+        content_encoding: first.content_encoding,
+        sha256: first.sha256.map(|h| h.into_vec()),
+    })
+}
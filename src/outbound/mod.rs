@@ -1 +1,15 @@
+pub mod asset_properties;
+pub mod asset_registry;
+pub mod authorization;
+pub mod batch_upload;
+pub mod canister_error;
+pub mod collections;
+pub mod configuration;
+pub mod fetch_asset;
+pub mod ownership;
+pub mod permissions;
+pub mod proposals;
+pub mod provision;
+pub mod review;
 pub mod upload_files;
+pub mod verify_asset;
@@ -0,0 +1,63 @@
+use crate::canister::asset_proxy::ValidationResult;
+use crate::outbound::canister_error::canister_error_message;
+use crate::outbound::permissions::{all_permissions, grant_permission, revoke_permission};
+use crate::state::canisters::Canisters;
+use anyhow::{anyhow, Error};
+use candid::Principal;
+use leptos::logging::log;
+
+/// Ask the canister whether `take_ownership` would succeed for the caller
+pub async fn validate_take_ownership(
+    canisters: &Canisters,
+    canister_id: Principal,
+) -> Result<Result<String, String>, Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    let result = asset_proxy.validate_take_ownership().await.map_err(|e| {
+        anyhow!(
+            "validate_take_ownership failed: {}",
+            canister_error_message(&e)
+        )
+    })?;
+
+    Ok(match result {
+        ValidationResult::Ok(message) => Ok(message),
+        ValidationResult::Err(message) => Err(message),
+    })
+}
+
+/// Revoke every permission on the canister and grant them all to the caller
+pub async fn take_ownership(canisters: &Canisters, canister_id: Principal) -> Result<(), Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    asset_proxy
+        .take_ownership()
+        .await
+        .map_err(|e| anyhow!("take_ownership failed: {}", canister_error_message(&e)))?;
+    log!("Took ownership of {}", canister_id);
+
+    Ok(())
+}
+
+/// After taking ownership, grant every permission to `new_owner` and drop the caller's own
+pub async fn hand_over(
+    canisters: &Canisters,
+    canister_id: Principal,
+    new_owner: Principal,
+) -> Result<(), Error> {
+    let caller = canisters
+        .agent
+        .get_principal()
+        .map_err(|e| anyhow!("Failed to get principal: {}", e))?;
+
+    for permission in all_permissions() {
+        grant_permission(canisters, canister_id, new_owner, permission).await?;
+    }
+    if caller != new_owner {
+        // ManagePermissions goes last, the other revocations need it
+        for permission in all_permissions() {
+            revoke_permission(canisters, canister_id, caller, permission).await?;
+        }
+    }
+    log!("Handed {} over to {}", canister_id, new_owner);
+
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use crate::canister::asset_proxy::{GrantPermission, ListPermitted, Permission, RevokePermission};
+use crate::outbound::canister_error::canister_error_message;
+use crate::state::canisters::Canisters;
+use anyhow::{anyhow, Error};
+use candid::Principal;
+use leptos::logging::log;
+
+/// Every permission the asset canister knows about, in display order
+pub fn all_permissions() -> Vec<Permission> {
+    vec![
+        Permission::Prepare,
+        Permission::Commit,
+        Permission::ManagePermissions,
+    ]
+}
+
+pub fn permission_name(permission: &Permission) -> &'static str {
+    match permission {
+        Permission::Prepare => "Prepare",
+        Permission::Commit => "Commit",
+        Permission::ManagePermissions => "ManagePermissions",
+    }
+}
+
+pub fn permission_from_name(name: &str) -> Option<Permission> {
+    all_permissions()
+        .into_iter()
+        .find(|permission| permission_name(permission) == name)
+}
+
+pub async fn list_permitted(
+    canisters: &Canisters,
+    canister_id: Principal,
+    permission: Permission,
+) -> Result<Vec<Principal>, Error> {
+    let name = permission_name(&permission);
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    asset_proxy
+        .list_permitted(ListPermitted { permission })
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "list_permitted({}) failed: {}",
+                name,
+                canister_error_message(&e)
+            )
+        })
+}
+
+/// Principals added with the legacy `authorize` call, which implies `Commit`
+pub async fn list_authorized(
+    canisters: &Canisters,
+    canister_id: Principal,
+) -> Result<Vec<Principal>, Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    asset_proxy
+        .list_authorized()
+        .await
+        .map_err(|e| anyhow!("list_authorized failed: {}", canister_error_message(&e)))
+}
+
+pub async fn grant_permission(
+    canisters: &Canisters,
+    canister_id: Principal,
+    to_principal: Principal,
+    permission: Permission,
+) -> Result<(), Error> {
+    let name = permission_name(&permission);
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    asset_proxy
+        .grant_permission(GrantPermission {
+            to_principal,
+            permission,
+        })
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "grant_permission({} to {}) failed: {}",
+                name,
+                to_principal,
+                canister_error_message(&e)
+            )
+        })?;
+    log!("Granted {} to {}", name, to_principal);
+
+    Ok(())
+}
+
+pub async fn revoke_permission(
+    canisters: &Canisters,
+    canister_id: Principal,
+    of_principal: Principal,
+    permission: Permission,
+) -> Result<(), Error> {
+    let name = permission_name(&permission);
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    asset_proxy
+        .revoke_permission(RevokePermission {
+            of_principal,
+            permission,
+        })
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "revoke_permission({} of {}) failed: {}",
+                name,
+                of_principal,
+                canister_error_message(&e)
+            )
+        })?;
+    log!("Revoked {} of {}", name, of_principal);
+
+    Ok(())
+}
+
+/// Legacy `authorize` call, which grants `Commit` without listing it as a permission
+pub async fn authorize(
+    canisters: &Canisters,
+    canister_id: Principal,
+    principal: Principal,
+) -> Result<(), Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    asset_proxy.authorize(principal).await.map_err(|e| {
+        anyhow!(
+            "authorize({}) failed: {}",
+            principal,
+            canister_error_message(&e)
+        )
+    })?;
+    log!("Authorized {}", principal);
+
+    Ok(())
+}
+
+pub async fn deauthorize(
+    canisters: &Canisters,
+    canister_id: Principal,
+    principal: Principal,
+) -> Result<(), Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    asset_proxy.deauthorize(principal).await.map_err(|e| {
+        anyhow!(
+            "deauthorize({}) failed: {}",
+            principal,
+            canister_error_message(&e)
+        )
+    })?;
+    log!("Deauthorized {}", principal);
+
+    Ok(())
+}
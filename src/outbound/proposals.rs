@@ -0,0 +1,156 @@
+use crate::canister::asset_proxy::{
+    BatchId, CommitProposedBatchArguments, ComputeEvidenceArguments, DeleteBatchArguments,
+    ValidationResult,
+};
+use crate::outbound::batch_upload::{stage_batch, BatchAsset};
+use crate::outbound::canister_error::{call_error, canister_error_message};
+use crate::state::canisters::Canisters;
+use anyhow::{anyhow, bail, Error};
+use candid::Principal;
+use leptos::logging::log;
+use serde_bytes::ByteBuf;
+
+/// Iterations the canister may spend per `compute_evidence` call
+const EVIDENCE_ITERATIONS: u16 = 1000;
+/// Give up if the evidence is still not ready after this many calls
+const MAX_EVIDENCE_CALLS: usize = 100;
+
+/// A staged batch waiting for a governance proposal to commit it
+#[derive(Clone, Debug)]
+pub struct ProposedBatch {
+    pub canister_id: Principal,
+    pub batch_id: BatchId,
+    pub evidence: Vec<u8>,
+    pub keys: Vec<String>,
+}
+
+/// Stage `assets`, propose the batch for commit and compute the evidence a
+/// `commit_proposed_batch` proposal must carry.
+pub async fn propose_batch(
+    canisters: &Canisters,
+    canister_id: Principal,
+    assets: Vec<BatchAsset>,
+) -> Result<ProposedBatch, Error> {
+    let keys = assets.iter().map(|asset| asset.key.clone()).collect();
+    let arguments = stage_batch(canisters, canister_id, assets).await?;
+    let batch_id = arguments.batch_id.clone();
+
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    asset_proxy
+        .propose_commit_batch(arguments)
+        .await
+        .map_err(|e| call_error(format!("Failed to propose batch {}", batch_id), e))?;
+    log!("Proposed batch {}", batch_id);
+
+    for call in 1..=MAX_EVIDENCE_CALLS {
+        let evidence = asset_proxy
+            .compute_evidence(ComputeEvidenceArguments {
+                batch_id: batch_id.clone(),
+                max_iterations: Some(EVIDENCE_ITERATIONS),
+            })
+            .await
+            .map_err(|e| {
+                call_error(
+                    format!("Failed to compute evidence for batch {}", batch_id),
+                    e,
+                )
+            })?;
+        if let Some(evidence) = evidence {
+            log!(
+                "Computed evidence for batch {} after {} call(s)",
+                batch_id,
+                call
+            );
+            return Ok(ProposedBatch {
+                canister_id,
+                batch_id,
+                evidence: evidence.into_vec(),
+                keys,
+            });
+        }
+    }
+
+    bail!(
+        "Evidence for batch {} was not ready after {} calls",
+        batch_id,
+        MAX_EVIDENCE_CALLS
+    )
+}
+
+/// Ask the canister whether a proposal to commit the batch would be accepted.
+/// `Err` holds the canister's explanation, e.g. when the batch was already committed.
+pub async fn validate_proposed_batch(
+    canisters: &Canisters,
+    canister_id: Principal,
+    batch_id: BatchId,
+    evidence: Vec<u8>,
+) -> Result<Result<String, String>, Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    let result = asset_proxy
+        .validate_commit_proposed_batch(CommitProposedBatchArguments {
+            batch_id,
+            evidence: ByteBuf::from(evidence),
+        })
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "validate_commit_proposed_batch failed: {}",
+                canister_error_message(&e)
+            )
+        })?;
+
+    Ok(match result {
+        ValidationResult::Ok(message) => Ok(message),
+        ValidationResult::Err(message) => Err(message),
+    })
+}
+
+/// Commit a proposed batch, which governance does once the proposal is adopted
+pub async fn commit_proposed_batch(
+    canisters: &Canisters,
+    canister_id: Principal,
+    batch_id: BatchId,
+    evidence: Vec<u8>,
+) -> Result<(), Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    asset_proxy
+        .commit_proposed_batch(CommitProposedBatchArguments {
+            batch_id: batch_id.clone(),
+            evidence: ByteBuf::from(evidence),
+        })
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "Failed to commit proposed batch {}: {}",
+                batch_id,
+                canister_error_message(&e)
+            )
+        })?;
+    log!("Committed proposed batch {}", batch_id);
+
+    Ok(())
+}
+
+/// Discard a proposed batch that governance rejected
+pub async fn delete_batch(
+    canisters: &Canisters,
+    canister_id: Principal,
+    batch_id: BatchId,
+) -> Result<(), Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    asset_proxy
+        .delete_batch(DeleteBatchArguments {
+            batch_id: batch_id.clone(),
+        })
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "Failed to delete batch {}: {}",
+                batch_id,
+                canister_error_message(&e)
+            )
+        })?;
+    log!("Deleted batch {}", batch_id);
+
+    Ok(())
+}
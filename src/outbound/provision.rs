@@ -0,0 +1,44 @@
+use crate::canister::provision::{ProvisionArgs, ProvisionResult};
+use crate::outbound::canister_error::canister_error_message;
+use crate::state::canisters::Canisters;
+use anyhow::{anyhow, bail, Error};
+use candid::Principal;
+use leptos::logging::log;
+
+/// Asset canister holding the files of `owner`'s collection, provisioning one if the
+/// provision canister has none yet. Without a collection id the owner's canister is used.
+pub async fn asset_canister_for(
+    canisters: &Canisters,
+    owner: Principal,
+    collection_id: Option<u64>,
+) -> Result<Principal, Error> {
+    let provision = canisters.provision_canister().await;
+    let args = ProvisionArgs {
+        owner,
+        collection_id,
+    };
+
+    let existing = provision
+        .get_asset_canister(args.clone())
+        .await
+        .map_err(|e| anyhow!("get_asset_canister failed: {}", canister_error_message(&e)))?;
+    if let Some(canister_id) = existing {
+        return Ok(canister_id);
+    }
+
+    match provision
+        .provision_asset_canister(args)
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "provision_asset_canister failed: {}",
+                canister_error_message(&e)
+            )
+        })? {
+        ProvisionResult::Ok(canister_id) => {
+            log!("Provisioned asset canister {} for {}", canister_id, owner);
+            Ok(canister_id)
+        }
+        ProvisionResult::Err(e) => bail!("Failed to provision an asset canister: {}", e),
+    }
+}
@@ -0,0 +1,109 @@
+use crate::canister::provision::{CarCollection, Result_, ReviewDecision, ReviewEvent};
+use crate::outbound::canister_error::canister_error_message;
+use crate::state::canisters::Canisters;
+use anyhow::{anyhow, bail, Error};
+use candid::Principal;
+use leptos::logging::log;
+
+/// Principals allowed to approve or reject submitted collections
+pub async fn list_reviewers(canisters: &Canisters) -> Result<Vec<Principal>, Error> {
+    let provision = canisters.provision_canister().await;
+    provision
+        .list_reviewers()
+        .await
+        .map_err(|e| anyhow!("list_reviewers failed: {}", canister_error_message(&e)))
+}
+
+/// Whether the logged in principal is one of the reviewers
+pub async fn is_reviewer(canisters: &Canisters) -> Result<bool, Error> {
+    let principal = canisters
+        .agent
+        .get_principal()
+        .map_err(|e| anyhow!("Failed to get principal: {}", e))?;
+    Ok(list_reviewers(canisters).await?.contains(&principal))
+}
+
+pub async fn add_reviewer(canisters: &Canisters, reviewer: Principal) -> Result<(), Error> {
+    let provision = canisters.provision_canister().await;
+    match provision
+        .add_reviewer(reviewer)
+        .await
+        .map_err(|e| anyhow!("add_reviewer failed: {}", canister_error_message(&e)))?
+    {
+        Result_::Ok => {
+            log!("Added reviewer {}", reviewer);
+            Ok(())
+        }
+        Result_::Err(e) => bail!("Failed to add reviewer {}: {}", reviewer, e),
+    }
+}
+
+pub async fn remove_reviewer(canisters: &Canisters, reviewer: Principal) -> Result<(), Error> {
+    let provision = canisters.provision_canister().await;
+    match provision
+        .remove_reviewer(reviewer)
+        .await
+        .map_err(|e| anyhow!("remove_reviewer failed: {}", canister_error_message(&e)))?
+    {
+        Result_::Ok => {
+            log!("Removed reviewer {}", reviewer);
+            Ok(())
+        }
+        Result_::Err(e) => bail!("Failed to remove reviewer {}: {}", reviewer, e),
+    }
+}
+
+/// Submitted collections waiting for a reviewer's decision
+pub async fn list_pending_collections(canisters: &Canisters) -> Result<Vec<CarCollection>, Error> {
+    let provision = canisters.provision_canister().await;
+    provision.list_pending_collections().await.map_err(|e| {
+        anyhow!(
+            "list_pending_collections failed: {}",
+            canister_error_message(&e)
+        )
+    })
+}
+
+/// Approve or reject a collection. Only reviewers may call this; the canister
+/// records the decision in the collection's review history.
+pub async fn review_collection(
+    canisters: &Canisters,
+    collection_id: u64,
+    decision: ReviewDecision,
+) -> Result<(), Error> {
+    let provision = canisters.provision_canister().await;
+    match provision
+        .review_car_collection(collection_id, decision)
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "review_car_collection failed: {}",
+                canister_error_message(&e)
+            )
+        })? {
+        Result_::Ok => {
+            log!("Reviewed collection {}", collection_id);
+            Ok(())
+        }
+        Result_::Err(e) => bail!("Failed to review collection {}: {}", collection_id, e),
+    }
+}
+
+/// Every decision taken on a collection, oldest first
+pub async fn review_history(
+    canisters: &Canisters,
+    collection_id: u64,
+) -> Result<Vec<ReviewEvent>, Error> {
+    let provision = canisters.provision_canister().await;
+    provision
+        .get_review_history(collection_id)
+        .await
+        .map_err(|e| anyhow!("get_review_history failed: {}", canister_error_message(&e)))
+}
+
+pub fn decision_text(decision: &ReviewDecision) -> String {
+    match decision {
+        ReviewDecision::Approve => "Approved".to_string(),
+        ReviewDecision::Reject(reason) => format!("Rejected: {}", reason),
+    }
+}
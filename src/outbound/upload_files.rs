@@ -1,16 +1,27 @@
-use crate::canister::asset_proxy::AssetProxy;
-use crate::canister::asset_proxy::StoreArg;
+use crate::canister::asset_proxy::{AssetProxy, Permission};
 use crate::canister::generated::asset_proxy;
 use crate::canister::provision;
-use crate::state::auth::AuthService;
+use crate::consts::upload::{DOCUMENT_MAX_AGE, IMMUTABLE_MAX_AGE};
+use crate::outbound::asset_registry::{asset_record, register_asset};
+use crate::outbound::authorization::{
+    check_upload_authorization, is_permission_reject, NotAuthorized,
+};
+use crate::outbound::batch_upload::{upload_batch, AssetOptions, BatchAsset};
+use crate::outbound::canister_error::is_expired_delegation;
+use crate::outbound::configuration::{get_configuration, UploadLimits};
+use crate::outbound::permissions::permission_name;
+use crate::outbound::proposals::{propose_batch, ProposedBatch};
+use crate::state::auth::AuthState;
 use crate::state::canisters::Canisters;
-use anyhow::Error; // Ensure you have anyhow for error handling
+use crate::utils::asset_url::AssetUrl;
+use crate::utils::to_hex;
+use anyhow::{anyhow, Error}; // Ensure you have anyhow for error handling
 use candid::Principal;
 use gloo::file::futures::read_as_bytes;
 use gloo_file::File;
+use ic_agent::AgentError;
 use leptos::logging::log;
 use leptos::*;
-use serde_bytes::ByteBuf;
 use sha2::{Digest, Sha256};
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -20,17 +31,77 @@ use web_sys::{Event, HtmlInputElement};
 // Define a type alias for clarity (optional)
 type AssetKey = String;
 
-pub async fn upload_files_from_input_event(
-    event: Event,
-    canisters: Rc<Canisters>,
-) -> Result<Vec<String>, Error> {
-    log!("Handling event: Canisters present.");
+/// The `CarCollection` field a file is uploaded for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UploadField {
+    Logo,
+    Images,
+    Documents,
+}
+
+impl UploadField {
+    /// Asset properties used when creating a file for this field.
+    ///
+    /// Logos and images are content-addressed, so they never change under their key
+    /// and can be cached for a long time. Documents are served as attachments under
+    /// their file name.
+    pub fn asset_options(self, file_name: &str) -> AssetOptions {
+        match self {
+            UploadField::Logo | UploadField::Images => AssetOptions {
+                max_age: Some(IMMUTABLE_MAX_AGE),
+                headers: Vec::new(),
+                enable_aliasing: Some(false),
+                allow_raw_access: Some(false),
+            },
+            UploadField::Documents => AssetOptions {
+                max_age: Some(DOCUMENT_MAX_AGE),
+                headers: vec![(
+                    "Content-Disposition".to_string(),
+                    format!("attachment; filename=\"{}\"", file_name.replace('"', "'")),
+                )],
+                enable_aliasing: Some(false),
+                allow_raw_access: Some(false),
+            },
+        }
+    }
+
+    /// Key the file is stored under in the asset canister. Documents keep their name
+    /// after the content hash, so same-named files of other collections don't clash.
+    pub fn asset_key(self, file_name: &str, sha256: &[u8]) -> AssetKey {
+        match self {
+            UploadField::Logo | UploadField::Images => match file_name.rsplit_once('.') {
+                Some((_, extension)) => format!("/img/{}.{}", to_hex(sha256), extension),
+                None => format!("/img/{}", to_hex(sha256)),
+            },
+            UploadField::Documents => format!("/file/{}/{}", to_hex(sha256), file_name),
+        }
+    }
+}
+
+/// Whether uploads are committed right away or proposed for a governance vote
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UploadMode {
+    Direct,
+    Propose,
+}
+
+/// Keys that were uploaded, the proposals to commit them in `Propose` mode and
+/// files skipped because they exceed the canister's configured limits
+#[derive(Clone, Debug, Default)]
+pub struct UploadOutcome {
+    pub asset_keys: Vec<AssetKey>,
+    pub proposals: Vec<ProposedBatch>,
+    pub warnings: Vec<String>,
+}
 
+/// Read the files selected in an `<input type="file">` into batch assets for `field`,
+/// each with the name of the file it was read from
+async fn read_batch_assets(event: &Event, field: UploadField) -> Vec<(String, BatchAsset)> {
     let input = event
         .target()
         .and_then(|t| t.dyn_into::<HtmlInputElement>().ok());
 
-    let mut asset_keys = Vec::new();
+    let mut assets = Vec::new();
 
     if let Some(input) = input {
         if let Some(file_list) = input.files() {
@@ -50,43 +121,198 @@ pub async fn upload_files_from_input_event(
                         }
                     };
                     let computed_hash = Sha256::digest(&bytes);
-                    let sha256 = Some(ByteBuf::from(computed_hash.to_vec()));
                     log!("Computed SHA-256 for {}: {:x}", file.name(), computed_hash);
 
-                    let store_arg = StoreArg {
-                        key: format!("/file-{}", file.name()),
-                        content_type: file.raw_mime_type(),
-                        content_encoding: "identity".to_string(),
-                        content: ByteBuf::from(bytes),
-                        sha256, // Provide the computed hash
-                                // aliased: Some(false), // Explicitly set aliased
-                    };
-                    let asset_id = "zcs7y-5iaaa-aaaam-adxfq-cai";
-
-                    let asset_principal =
-                        Principal::from_text(asset_id).expect("Invalid principal");
-                    log!("Uploading file: {}, Principal: {}", store_arg.key, asset_id);
-
-                    log!("file name: {}  ", file.name());
-                    // Call `store_asset` on the Canisters instance
-                    match canisters
-                        .store_asset(asset_principal, store_arg.clone())
-                        .await
-                    {
-                        Ok(_) => {
-                            log!("Successfully uploaded asset: {}", store_arg.key);
-                            log!("https://{}.raw.icp0.io{}", asset_id, store_arg.key);
-                            log!("https://{}.icp0.io{}", asset_id, store_arg.key);
-                            asset_keys.push(store_arg.key);
-                        }
-                        Err(e) => {
-                            log!("Failed to upload asset {}: {:?}", store_arg.key, e);
+                    assets.push((
+                        file.name(),
+                        BatchAsset {
+                            key: field.asset_key(&file.name(), &computed_hash),
+                            content_type: file.raw_mime_type(),
+                            content: bytes,
+                            sha256: computed_hash.to_vec(),
+                            options: field.asset_options(&file.name()),
+                        },
+                    ));
+                }
+            }
+        }
+    }
+
+    assets
+}
+
+fn not_authorized(canisters: &Canisters, canister_id: Principal, permission: &Permission) -> Error {
+    NotAuthorized {
+        principal: canisters
+            .agent
+            .get_principal()
+            .unwrap_or_else(|_| Principal::anonymous()),
+        canister_id,
+        permission: permission_name(permission),
+    }
+    .into()
+}
+
+/// Whether a staging or commit call failed on an expired delegation
+fn expired_delegation(e: &Error) -> bool {
+    e.downcast_ref::<AgentError>()
+        .is_some_and(is_expired_delegation)
+}
+
+/// Canisters of a live session, waiting while it is expired
+async fn session_canisters(auth: AuthState) -> Result<Rc<Canisters>, Error> {
+    auth.active_canisters()
+        .await
+        .map_err(|e| anyhow!("Upload stopped: {}", e))
+}
+
+/// Upload the selected files to `canister_id`. In `Direct` mode `on_committed` is
+/// called with the file name and key of every file as soon as its batch commits.
+///
+/// Each batch is sent with the canisters of the current session. If the delegation
+/// expires midway the upload pauses until `auth` is renewed and resumes with the
+/// batch that was refused.
+pub async fn upload_files_from_input_event(
+    event: Event,
+    auth: AuthState,
+    canisters: Rc<Canisters>,
+    canister_id: Principal,
+    field: UploadField,
+    mode: UploadMode,
+    on_committed: impl Fn(&str, &AssetKey),
+) -> Result<UploadOutcome, Error> {
+    log!("Handling event: Canisters present.");
+    // Keeps an idle session from locking or logging out under the upload
+    let _upload = auth.track_upload();
+
+    let asset_id = canister_id.to_text();
+    let asset_principal = canister_id;
+    let permission = match mode {
+        UploadMode::Direct => Permission::Commit,
+        UploadMode::Propose => Permission::Prepare,
+    };
+    if !check_upload_authorization(&canisters, asset_principal, permission.clone()).await? {
+        log!(
+            "Could not confirm {} permission, trying the upload anyway",
+            permission_name(&permission)
+        );
+    }
+
+    // Read the limits up front so oversized uploads fail before any chunk is sent
+    let limits = match get_configuration(&canisters, asset_principal).await {
+        Ok(limits) => limits,
+        Err(e) => {
+            log!("Could not read upload limits, assuming none: {:?}", e);
+            UploadLimits::default()
+        }
+    };
+
+    let assets = read_batch_assets(&event, field).await;
+
+    let owner = canisters
+        .agent
+        .get_principal()
+        .map_err(|e| anyhow!("Failed to get principal: {}", e))?;
+    if mode == UploadMode::Propose {
+        log!(
+            "Proposing {} file(s) for commit, Principal: {}",
+            assets.len(),
+            asset_id
+        );
+        let mut outcome = UploadOutcome::default();
+        let assets = assets.into_iter().map(|(_, asset)| asset).collect();
+        for batch in limits.plan_batches(assets)? {
+            let records: Vec<_> = batch
+                .iter()
+                .map(|asset| asset_record(asset_principal, owner, asset))
+                .collect();
+            let canisters = session_canisters(auth).await?;
+            let proposed = match propose_batch(&canisters, asset_principal, batch.clone()).await {
+                Err(e) if expired_delegation(&e) => {
+                    log!("Session expired, pausing proposal until it is renewed");
+                    auth.mark_expired();
+                    let canisters = session_canisters(auth).await?;
+                    propose_batch(&canisters, asset_principal, batch).await
+                }
+                proposed => proposed,
+            };
+            match proposed {
+                Ok(proposal) => {
+                    log!(
+                        "Proposed batch {} with evidence {}",
+                        proposal.batch_id,
+                        to_hex(&proposal.evidence)
+                    );
+                    // Registered now, since the commit happens later through governance
+                    for record in records {
+                        if let Err(e) = register_asset(&canisters, record).await {
+                            log!("Failed to register proposed asset: {:?}", e);
+                            outcome.warnings.push(e.to_string());
                         }
                     }
+                    outcome.asset_keys.extend(proposal.keys.iter().cloned());
+                    outcome.proposals.push(proposal);
                 }
+                Err(e) if is_permission_reject(&e.to_string()) => {
+                    log!("Proposal rejected: {:?}", e);
+                    return Err(not_authorized(&canisters, asset_principal, &permission));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        return Ok(outcome);
+    }
+
+    let mut asset_keys = Vec::new();
+    let mut warnings = Vec::new();
+    for (file_name, asset) in assets {
+        if let Some(reason) = limits.rejects(&asset) {
+            log!("Skipping {}: {}", asset.key, reason);
+            warnings.push(reason);
+            continue;
+        }
+
+        let key = asset.key.clone();
+        let record = asset_record(asset_principal, owner, &asset);
+        log!("Uploading file: {}, Principal: {}", key, asset_id);
+
+        let mut canisters = session_canisters(auth).await?;
+        let mut uploaded = upload_batch(&canisters, asset_principal, vec![asset.clone()]).await;
+        if matches!(&uploaded, Err(e) if expired_delegation(&e)) {
+            log!(
+                "Session expired, pausing upload of {} until it is renewed",
+                key
+            );
+            auth.mark_expired();
+            canisters = session_canisters(auth).await?;
+            uploaded = upload_batch(&canisters, asset_principal, vec![asset]).await;
+        }
+
+        match uploaded {
+            Ok(_) => {
+                log!("Successfully uploaded asset: {}", key);
+                log!("{}", AssetUrl::for_network().resolve(asset_principal, &key));
+                // The upload itself succeeded, so a missing record is only a warning
+                if let Err(e) = register_asset(&canisters, record).await {
+                    log!("Failed to register asset {}: {:?}", key, e);
+                    warnings.push(e.to_string());
+                }
+                on_committed(&file_name, &key);
+                asset_keys.push(key);
+            }
+            Err(e) if is_permission_reject(&e.to_string()) => {
+                log!("Upload of {} rejected: {:?}", key, e);
+                return Err(not_authorized(&canisters, asset_principal, &permission));
+            }
+            Err(e) => {
+                log!("Failed to upload asset {}: {:?}", key, e);
             }
         }
     }
 
-    Ok(asset_keys)
+    Ok(UploadOutcome {
+        asset_keys,
+        proposals: Vec::new(),
+        warnings,
+    })
 }
@@ -0,0 +1,88 @@
+use crate::canister::asset_proxy::CertifiedTreeArg;
+use crate::outbound::fetch_asset::fetch_asset;
+use crate::state::canisters::Canisters;
+use anyhow::{anyhow, bail, Error};
+use candid::Principal;
+use ic_certification::{Certificate, HashTree, LookupResult};
+use leptos::logging::log;
+use sha2::{Digest, Sha256};
+
+/// An asset whose downloaded content matched the hash certified by the subnet
+#[derive(Clone, Debug)]
+pub struct VerifiedAsset {
+    pub key: String,
+    pub content_encoding: String,
+    pub sha256: Vec<u8>,
+}
+
+/// Verify `key` against the asset canister's `certified_tree`.
+///
+/// The certificate is checked against the agent's root key, the tree against the
+/// canister's `certified_data`, and the `http_assets/<key>` leaf against the sha256
+/// of the content downloaded in `encoding`. The v1 tree only certifies the preferred
+/// encoding of each key, which is `identity` for everything the uploader stores.
+pub async fn verify_certified_asset(
+    canisters: &Canisters,
+    canister_id: Principal,
+    key: &str,
+    encoding: &str,
+) -> Result<VerifiedAsset, Error> {
+    let asset_proxy = canisters.asset_proxy_canister(canister_id).await;
+    let certified = asset_proxy
+        .certified_tree(CertifiedTreeArg {})
+        .await
+        .map_err(|e| anyhow!("Failed to fetch certified tree: {:?}", e))?;
+
+    let certificate: Certificate = serde_cbor::from_slice(&certified.certificate)?;
+    canisters
+        .agent
+        .verify(&certificate, canister_id)
+        .map_err(|e| anyhow!("Certificate verification failed: {}", e))?;
+
+    let certified_data = match certificate.tree.lookup_path([
+        b"canister".as_slice(),
+        canister_id.as_slice(),
+        b"certified_data".as_slice(),
+    ]) {
+        LookupResult::Found(data) => data,
+        other => bail!(
+            "Certificate has no certified_data for {}: {:?}",
+            canister_id,
+            other
+        ),
+    };
+
+    let tree: HashTree = serde_cbor::from_slice(&certified.tree)?;
+    if tree.digest().as_slice() != certified_data {
+        bail!(
+            "Asset tree does not match the certified data of {}",
+            canister_id
+        );
+    }
+
+    let certified_hash = match tree.lookup_path([b"http_assets".as_slice(), key.as_bytes()]) {
+        LookupResult::Found(hash) => hash.to_vec(),
+        other => bail!("Asset {} is not in the certified tree: {:?}", key, other),
+    };
+
+    let asset = fetch_asset(canisters, canister_id, key, encoding).await?;
+    if asset.content_encoding != encoding {
+        bail!(
+            "Asset {} was served as {} instead of {}",
+            key,
+            asset.content_encoding,
+            encoding
+        );
+    }
+    let computed_hash = Sha256::digest(&asset.content);
+    if computed_hash.as_slice() != certified_hash.as_slice() {
+        bail!("Content of {} does not match its certified hash", key);
+    }
+    log!("Verified {} ({}): {:x}", key, encoding, computed_hash);
+
+    Ok(VerifiedAsset {
+        key: asset.key,
+        content_encoding: asset.content_encoding,
+        sha256: certified_hash,
+    })
+}
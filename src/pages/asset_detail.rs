@@ -0,0 +1,294 @@
+use crate::components::edit_mode::{EditMode, EditModeSelect};
+use crate::components::header::Header;
+use crate::components::invalid_canister::InvalidCanister;
+use crate::components::verified_badge::VerifiedBadge;
+use crate::outbound::asset_properties::{
+    format_headers, get_asset_properties, parse_headers, set_asset_properties, AssetProperties,
+    AssetPropertiesEdit,
+};
+use crate::outbound::asset_registry::get_registered_asset;
+use crate::state::auth::use_auth;
+use crate::state::canisters::Canisters;
+use crate::utils::asset_url::asset_url;
+use crate::utils::use_asset_canister_query;
+use anyhow::{anyhow, Error};
+use leptos::*;
+use leptos_router::*;
+use std::rc::Rc;
+
+fn parse_bool(value: &str) -> Result<bool, Error> {
+    match value {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(anyhow!("Expected true or false, got {}", value)),
+    }
+}
+
+/// Asset detail panel reading and editing `get_asset_properties`/`set_asset_properties`
+#[component]
+pub fn AssetDetail() -> impl IntoView {
+    let params = use_params_map();
+    let asset_key = create_memo(move |_| {
+        format!(
+            "/{}",
+            params.with(|p| p.get("key").cloned().unwrap_or_default())
+        )
+    });
+
+    let canisters_signal = use_context::<RwSignal<Option<Rc<Canisters>>>>()
+        .expect("Canisters signal should be provided by AuthServiceProvider");
+    let auth = use_auth();
+    let canister_id = match use_asset_canister_query() {
+        Ok(canister_id) => canister_id,
+        Err(error) => return view! { <InvalidCanister error=error /> }.into_view(),
+    };
+
+    let (reload, set_reload) = create_signal(0);
+    let properties = create_local_resource(
+        move || (canisters_signal.get(), asset_key.get(), reload.get()),
+        move |(canisters, key, _)| async move {
+            let canisters = canisters?;
+            Some(
+                get_asset_properties(&canisters, canister_id, &key)
+                    .await
+                    .map_err(|e| e.to_string()),
+            )
+        },
+    );
+
+    // Who uploaded the asset, as recorded by the provision canister
+    let registration = create_local_resource(
+        move || (canisters_signal.get(), asset_key.get()),
+        move |(canisters, key)| async move {
+            let canisters = canisters?;
+            Some(
+                get_registered_asset(&canisters, canister_id, &key)
+                    .await
+                    .map_err(|e| e.to_string()),
+            )
+        },
+    );
+
+    let max_age_mode = create_rw_signal(EditMode::Unchanged);
+    let max_age = create_rw_signal(String::new());
+    let headers_mode = create_rw_signal(EditMode::Unchanged);
+    let headers = create_rw_signal(String::new());
+    let raw_access_mode = create_rw_signal(EditMode::Unchanged);
+    let raw_access = create_rw_signal("false".to_string());
+    let aliased_mode = create_rw_signal(EditMode::Unchanged);
+    let aliased = create_rw_signal("false".to_string());
+
+    // Prefill the inputs with the current values whenever they are (re)loaded
+    create_effect(move |_| {
+        if let Some(Some(Ok(current))) = properties.get() {
+            let AssetProperties {
+                max_age: current_max_age,
+                headers: current_headers,
+                allow_raw_access,
+                is_aliased,
+            } = current;
+            max_age.set(current_max_age.map(|v| v.to_string()).unwrap_or_default());
+            headers.set(
+                current_headers
+                    .as_deref()
+                    .map(format_headers)
+                    .unwrap_or_default(),
+            );
+            raw_access.set(allow_raw_access.unwrap_or(false).to_string());
+            aliased.set(is_aliased.unwrap_or(false).to_string());
+            max_age_mode.set(EditMode::Unchanged);
+            headers_mode.set(EditMode::Unchanged);
+            raw_access_mode.set(EditMode::Unchanged);
+            aliased_mode.set(EditMode::Unchanged);
+        }
+    });
+
+    let (status, set_status) = create_signal(String::new());
+    let save = create_action(move |edit: &AssetPropertiesEdit| {
+        let edit = edit.clone();
+        async move {
+            let canisters = match auth.require_login().await {
+                Ok(canisters) => canisters,
+                Err(e) => {
+                    set_status.set(e);
+                    return;
+                }
+            };
+            match set_asset_properties(&canisters, canister_id, &asset_key.get_untracked(), edit)
+                .await
+            {
+                Ok(()) => {
+                    set_status.set("Properties saved.".to_string());
+                    set_reload.update(|r| *r += 1);
+                }
+                Err(e) => set_status.set(format!("Save failed: {}", e)),
+            }
+        }
+    });
+
+    let on_save = move |_| {
+        let edit = (|| -> Result<AssetPropertiesEdit, Error> {
+            Ok(AssetPropertiesEdit {
+                max_age: max_age_mode.get_untracked().edit(|| {
+                    max_age
+                        .get_untracked()
+                        .trim()
+                        .parse::<u64>()
+                        .map_err(|e| anyhow!("Invalid max_age: {}", e))
+                })?,
+                headers: headers_mode
+                    .get_untracked()
+                    .edit(|| parse_headers(&headers.get_untracked()))?,
+                allow_raw_access: raw_access_mode
+                    .get_untracked()
+                    .edit(|| parse_bool(&raw_access.get_untracked()))?,
+                is_aliased: aliased_mode
+                    .get_untracked()
+                    .edit(|| parse_bool(&aliased.get_untracked()))?,
+            })
+        })();
+        match edit {
+            Ok(edit) => save.dispatch(edit),
+            Err(e) => set_status.set(e.to_string()),
+        }
+    };
+
+    view! {
+        <Header />
+        <div class="container pt-24">
+            <h1 class="text-xl font-semibold">"Asset " {move || asset_key.get()}</h1>
+            <div class="flex gap-4 items-center mt-2">
+                <a
+                    href=move || asset_url(Some(canister_id), &asset_key.get())
+                    class="text-blue-500 underline"
+                    target="_blank"
+                >
+                    "Open"
+                </a>
+                {move || view! { <VerifiedBadge asset_key=asset_key.get() canister_id=Some(canister_id) /> }}
+            </div>
+
+            <Suspense fallback=|| ()>
+                {move || match registration.get() {
+                    Some(Some(Ok(Some(asset)))) => {
+                        let length: u64 = asset.encodings.iter().map(|(_, e)| e.total_length).sum();
+                        view! {
+                            <p class="mt-2 text-sm text-gray-600">
+                                "Uploaded by " <code>{asset.owner.to_text()}</code> ", "
+                                {asset.content_type} ", " {length} " bytes"
+                            </p>
+                        }
+                            .into_view()
+                    }
+                    Some(Some(Ok(None))) => {
+                        view! {
+                            <p class="mt-2 text-sm text-gray-600">
+                                "Not registered with the provision canister"
+                            </p>
+                        }
+                            .into_view()
+                    }
+                    Some(Some(Err(e))) => {
+                        view! { <p class="mt-2 text-sm text-red-500">{e}</p> }.into_view()
+                    }
+                    _ => ().into_view(),
+                }}
+            </Suspense>
+
+            <Suspense fallback=|| view! { <p>"Loading properties..."</p> }>
+                {move || match properties.get() {
+                    Some(None) => view! { <p>"Connecting to the network..."</p> }.into_view(),
+                    Some(Some(Err(e))) => {
+                        view! { <p class="text-red-500">{e}</p> }.into_view()
+                    }
+                    _ => ().into_view(),
+                }}
+            </Suspense>
+
+            <form class="flex flex-col gap-4 mt-4" on:submit=|e| e.prevent_default()>
+                <label class="flex flex-col gap-1">
+                    <span class="text-sm font-medium text-gray-900">"max_age (seconds)"</span>
+                    <div class="flex gap-2">
+                        <EditModeSelect mode=max_age_mode />
+                        <input
+                            type="number"
+                            min="0"
+                            prop:value=move || max_age.get()
+                            on:input=move |e| max_age.set(event_target_value(&e))
+                            disabled=move || max_age_mode.get() != EditMode::Set
+                            class="block w-full rounded-md border-gray-300 shadow-sm"
+                        />
+                    </div>
+                </label>
+
+                <label class="flex flex-col gap-1">
+                    <span class="text-sm font-medium text-gray-900">
+                        "headers (one \"Name: value\" per line)"
+                    </span>
+                    <div class="flex gap-2">
+                        <EditModeSelect mode=headers_mode />
+                        <textarea
+                            rows="4"
+                            prop:value=move || headers.get()
+                            on:input=move |e| headers.set(event_target_value(&e))
+                            disabled=move || headers_mode.get() != EditMode::Set
+                            class="block w-full font-mono text-sm rounded-md border-gray-300 shadow-sm"
+                        ></textarea>
+                    </div>
+                </label>
+
+                <label class="flex flex-col gap-1">
+                    <span class="text-sm font-medium text-gray-900">"allow_raw_access"</span>
+                    <div class="flex gap-2">
+                        <EditModeSelect mode=raw_access_mode />
+                        <select
+                            on:change=move |e| raw_access.set(event_target_value(&e))
+                            disabled=move || raw_access_mode.get() != EditMode::Set
+                            class="rounded-md border-gray-300"
+                        >
+                            <option value="true" selected=move || raw_access.get() == "true">
+                                "true"
+                            </option>
+                            <option value="false" selected=move || raw_access.get() == "false">
+                                "false"
+                            </option>
+                        </select>
+                    </div>
+                </label>
+
+                <label class="flex flex-col gap-1">
+                    <span class="text-sm font-medium text-gray-900">"is_aliased"</span>
+                    <div class="flex gap-2">
+                        <EditModeSelect mode=aliased_mode />
+                        <select
+                            on:change=move |e| aliased.set(event_target_value(&e))
+                            disabled=move || aliased_mode.get() != EditMode::Set
+                            class="rounded-md border-gray-300"
+                        >
+                            <option value="true" selected=move || aliased.get() == "true">
+                                "true"
+                            </option>
+                            <option value="false" selected=move || aliased.get() == "false">
+                                "false"
+                            </option>
+                        </select>
+                    </div>
+                </label>
+
+                <Show when=move || !status.get().is_empty()>
+                    <div class="text-sm text-gray-700">{move || status.get()}</div>
+                </Show>
+
+                <button
+                    type="button"
+                    on:click=on_save
+                    disabled=move || save.pending().get()
+                    class="py-2 px-4 font-semibold text-white bg-blue-500 rounded hover:bg-blue-600"
+                >
+                    {move || if save.pending().get() { "Saving..." } else { "Save properties" }}
+                </button>
+            </form>
+        </div>
+    }
+    .into_view()
+}
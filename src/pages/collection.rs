@@ -0,0 +1,258 @@
+use crate::components::header::Header;
+use crate::components::image_gallery::ImageGallery;
+use crate::components::verified_badge::VerifiedBadge;
+use crate::outbound::collections::{get_collection, save_gallery, status_text};
+use crate::state::auth::use_auth;
+use crate::state::canisters::{default_asset_canister, Canisters};
+use crate::state::collection::{CollectionDocument, CollectionImage, DocumentCategory};
+use crate::utils::asset_url::{asset_url, document_href};
+use crate::utils::validation::document_flags;
+use crate::utils::{date_input_value, now_nanos};
+use candid::Principal;
+use leptos::*;
+use leptos_router::*;
+use std::rc::Rc;
+
+/// A submitted collection as stored by the provision canister
+#[component]
+pub fn CollectionDetail() -> impl IntoView {
+    let params = use_params_map();
+    let id =
+        create_memo(move |_| params.with(|p| p.get("id").and_then(|id| id.parse::<u64>().ok())));
+
+    let canisters_signal = use_context::<RwSignal<Option<Rc<Canisters>>>>()
+        .expect("Canisters signal should be provided by AuthServiceProvider");
+    let auth = use_auth();
+    // Refetched when the id changes or the network becomes reachable, not on every
+    // login or logout, which only decides whether the gallery can be edited
+    let collection = create_local_resource(
+        move || (id.get(), canisters_signal.with(Option::is_some)),
+        move |(id, _)| async move {
+            let canisters = canisters_signal.get_untracked()?;
+            let Some(id) = id else {
+                return Some(Err("Invalid collection id".to_string()));
+            };
+            Some(
+                get_collection(&canisters, id)
+                    .await
+                    .map_err(|e| e.to_string()),
+            )
+        },
+    );
+
+    // Gallery as edited by the owner, written back with `save`. Kept outside the
+    // fetched collection so a re-fetch doesn't drop edits that weren't saved yet.
+    let images = create_rw_signal(Vec::<CollectionImage>::new());
+    let logo = create_rw_signal(String::new());
+    // Collection whose gallery has unsaved edits
+    let edited = create_rw_signal(None::<u64>);
+    create_effect(move |_| {
+        if let Some(Some(Ok(Some(stored)))) = collection.get() {
+            if edited.get_untracked() != Some(stored.id) {
+                images.set(
+                    stored
+                        .images
+                        .iter()
+                        .cloned()
+                        .map(CollectionImage::from)
+                        .collect(),
+                );
+                logo.set(stored.logo);
+            }
+        }
+    });
+
+    view! {
+        <Header />
+        <div class="container pt-24">
+            <Suspense fallback=|| view! { <p>"Loading collection..."</p> }>
+                {move || match collection.get() {
+                    Some(None) => view! { <p>"Connecting to the network..."</p> }.into_view(),
+                    Some(Some(Ok(None))) => {
+                        view! { <p>"No collection with this id."</p> }.into_view()
+                    }
+                    Some(Some(Ok(Some(collection)))) => {
+                        let canister_id = collection
+                            .asset_canister
+                            .unwrap_or_else(default_asset_canister);
+                        let id = collection.id;
+                        let owner = collection.owner;
+                        let is_owner = move || auth.principal.get() == Some(owner);
+                        let documents = collection
+                            .documents
+                            .iter()
+                            .cloned()
+                            .map(CollectionDocument::from)
+                            .collect::<Vec<_>>();
+                        let (gallery_status, set_gallery_status) = create_signal(None::<Result<(), String>>);
+                        let save = create_action(move |(images, logo): &(Vec<CollectionImage>, String)| {
+                            let (images, logo) = (images.clone(), logo.clone());
+                            async move {
+                                let saved = match auth.require_login().await {
+                                    Ok(canisters) => {
+                                        save_gallery(&canisters, id, images, logo)
+                                            .await
+                                            .map_err(|e| e.to_string())
+                                    }
+                                    Err(e) => Err(e),
+                                };
+                                if saved.is_ok() {
+                                    edited.set(None);
+                                }
+                                set_gallery_status.set(Some(saved));
+                            }
+                        });
+                        view! {
+                            <h1 class="text-xl font-semibold">{collection.name.clone()}</h1>
+                            <p class="text-sm text-gray-600">
+                                {collection.model.clone()} " · #" {collection.id} " · "
+                                {status_text(&collection.status)}
+                            </p>
+
+                            <Show when=move || !logo.get().is_empty()>
+                                <div class="relative p-2 mt-4 rounded border h-[14rem] w-[14rem]">
+                                    <img
+                                        src=move || asset_url(Some(canister_id), &logo.get())
+                                        alt="Logo"
+                                        class="object-contain w-full h-full rounded-md"
+                                    />
+                                    <div class="absolute bottom-2 left-2">
+                                        {move || {
+                                            view! {
+                                                <VerifiedBadge
+                                                    asset_key=logo.get()
+                                                    canister_id=Some(canister_id)
+                                                />
+                                            }
+                                        }}
+                                    </div>
+                                </div>
+                            </Show>
+
+                            <h2 class="mt-4 font-medium">"Images"</h2>
+                            <div class="flex overflow-x-auto gap-2 p-2 rounded border">
+                                {move || if is_owner() {
+                                    view! {
+                                        <ImageGallery
+                                            images=images
+                                            canister_id=Some(canister_id)
+                                            cover=logo
+                                            on_change=move |new_images: Vec<CollectionImage>| {
+                                                images.set(new_images);
+                                                edited.set(Some(id));
+                                                set_gallery_status.set(None);
+                                            }
+                                            on_set_cover=move |key: String| {
+                                                logo.set(key);
+                                                edited.set(Some(id));
+                                                set_gallery_status.set(None);
+                                            }
+                                        />
+                                    }
+                                } else {
+                                    view! {
+                                        <ImageGallery
+                                            images=images
+                                            canister_id=Some(canister_id)
+                                            cover=logo
+                                        />
+                                    }
+                                }}
+                            </div>
+                            <Show when=is_owner>
+                                <div class="flex gap-4 items-center mt-2">
+                                    <button
+                                        type="button"
+                                        on:click=move |_| {
+                                            save.dispatch((images.get_untracked(), logo.get_untracked()))
+                                        }
+                                        disabled=move || save.pending().get()
+                                        class="py-1 px-3 text-sm font-semibold text-white bg-blue-500 rounded hover:bg-blue-600"
+                                    >
+                                        "Save gallery"
+                                    </button>
+                                    {move || match gallery_status.get() {
+                                        Some(Ok(())) => {
+                                            view! { <span class="text-sm text-green-700">"Saved"</span> }
+                                                .into_view()
+                                        }
+                                        Some(Err(e)) => {
+                                            view! { <span class="text-sm text-red-500">{e}</span> }
+                                                .into_view()
+                                        }
+                                        None => ().into_view(),
+                                    }}
+                                </div>
+                            </Show>
+
+                            <h2 class="mt-4 font-medium">"Documents"</h2>
+                            <ul class="text-sm text-amber-700">
+                                {document_flags(&documents, now_nanos())
+                                    .into_iter()
+                                    .map(|flag| view! { <li>{flag.message()}</li> })
+                                    .collect_view()}
+                            </ul>
+                            {DocumentCategory::ALL
+                                .into_iter()
+                                .filter_map(|category| {
+                                    let group = documents
+                                        .iter()
+                                        .filter(|document| document.category == category)
+                                        .cloned()
+                                        .collect::<Vec<_>>();
+                                    (!group.is_empty())
+                                        .then(|| {
+                                            view! {
+                                                <h3 class="mt-2 text-sm font-medium text-gray-700">
+                                                    {category.label()}
+                                                </h3>
+                                                <ul class="flex flex-col gap-1 mt-1">
+                                                    {group
+                                                        .into_iter()
+                                                        .map(|document| {
+                                                            view! {
+                                                                <DocumentEntry document canister_id />
+                                                            }
+                                                        })
+                                                        .collect_view()}
+                                                </ul>
+                                            }
+                                        })
+                                })
+                                .collect_view()}
+                        }
+                            .into_view()
+                    }
+                    Some(Some(Err(e))) => view! { <p class="text-red-500">{e}</p> }.into_view(),
+                    None => ().into_view(),
+                }}
+            </Suspense>
+        </div>
+    }
+}
+
+/// A document link with its verification, issuer and expiry date
+#[component]
+fn DocumentEntry(document: CollectionDocument, canister_id: Principal) -> impl IntoView {
+    let expiry = document.expires_at.map(|expires_at| {
+        let expired = expires_at <= now_nanos();
+        view! {
+            <span class=if expired { "text-red-500" } else { "text-gray-600" }>
+                {if expired { "Expired " } else { "Expires " }}
+                {date_input_value(expires_at)}
+            </span>
+        }
+    });
+
+    view! {
+        <li class="flex gap-3 items-baseline text-sm">
+            <a href=document_href(Some(canister_id), &document.key) class="text-blue-500 underline">
+                {document.key.clone()}
+            </a>
+            <VerifiedBadge asset_key=document.key.clone() canister_id=Some(canister_id) />
+            {(!document.issuer.is_empty())
+                .then(|| view! { <span class="text-gray-600">"Issued by " {document.issuer.clone()}</span> })}
+            {expiry}
+        </li>
+    }
+}
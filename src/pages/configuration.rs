@@ -0,0 +1,207 @@
+use crate::components::admin_nav::AdminNav;
+use crate::components::edit_mode::{EditMode, EditModeSelect};
+use crate::components::header::Header;
+use crate::components::invalid_canister::InvalidCanister;
+use crate::outbound::configuration::{
+    configure, get_configuration, validate_configure, ConfigurationEdit,
+};
+use crate::state::auth::use_auth;
+use crate::state::canisters::Canisters;
+use crate::utils::use_asset_canister_query;
+use anyhow::{anyhow, Error};
+use leptos::*;
+use std::rc::Rc;
+
+#[derive(Clone, Copy, PartialEq)]
+enum ConfigureAction {
+    Validate,
+    Apply,
+}
+
+fn limit_text(limit: Option<u64>) -> String {
+    limit
+        .map(|v| v.to_string())
+        .unwrap_or_else(|| "unlimited".to_string())
+}
+
+/// Admin panel showing `get_configuration` and editing the limits with `configure`
+#[component]
+pub fn Configuration() -> impl IntoView {
+    let canisters_signal = use_context::<RwSignal<Option<Rc<Canisters>>>>()
+        .expect("Canisters signal should be provided by AuthServiceProvider");
+    let auth = use_auth();
+    let canister_id = match use_asset_canister_query() {
+        Ok(canister_id) => canister_id,
+        Err(error) => return view! { <InvalidCanister error=error /> }.into_view(),
+    };
+
+    let (reload, set_reload) = create_signal(0);
+    let configuration = create_local_resource(
+        move || (canisters_signal.get(), reload.get()),
+        move |(canisters, _)| async move {
+            let canisters = canisters?;
+            Some(
+                get_configuration(&canisters, canister_id)
+                    .await
+                    .map_err(|e| e.to_string()),
+            )
+        },
+    );
+
+    let fields = [
+        (
+            "max_batches",
+            create_rw_signal(EditMode::Unchanged),
+            create_rw_signal(String::new()),
+        ),
+        (
+            "max_chunks",
+            create_rw_signal(EditMode::Unchanged),
+            create_rw_signal(String::new()),
+        ),
+        (
+            "max_bytes",
+            create_rw_signal(EditMode::Unchanged),
+            create_rw_signal(String::new()),
+        ),
+    ];
+
+    let (status, set_status) = create_signal(None::<Result<String, String>>);
+    let run = create_action(
+        move |(action, edit): &(ConfigureAction, ConfigurationEdit)| {
+            let (action, edit) = (*action, edit.clone());
+            async move {
+                let canisters = match auth.require_login().await {
+                    Ok(canisters) => canisters,
+                    Err(e) => {
+                        set_status.set(Some(Err(e)));
+                        return;
+                    }
+                };
+                let result = match action {
+                    ConfigureAction::Validate => {
+                        match validate_configure(&canisters, canister_id, edit).await {
+                            Ok(Ok(message)) => Ok(format!("Valid: {}", message)),
+                            Ok(Err(message)) => Err(format!("Invalid: {}", message)),
+                            Err(e) => Err(e.to_string()),
+                        }
+                    }
+                    ConfigureAction::Apply => configure(&canisters, canister_id, edit)
+                        .await
+                        .map(|()| "Configuration updated.".to_string())
+                        .map_err(|e| e.to_string()),
+                };
+                if action == ConfigureAction::Apply && result.is_ok() {
+                    set_reload.update(|r| *r += 1);
+                }
+                set_status.set(Some(result));
+            }
+        },
+    );
+
+    let on_action = move |action: ConfigureAction| {
+        let parse = |(name, mode, value): &(&str, RwSignal<EditMode>, RwSignal<String>)| {
+            mode.get_untracked().edit(|| {
+                value
+                    .get_untracked()
+                    .trim()
+                    .parse::<u64>()
+                    .map_err(|e| anyhow!("Invalid {}: {}", name, e))
+            })
+        };
+        let edit = (|| -> Result<ConfigurationEdit, Error> {
+            Ok(ConfigurationEdit {
+                max_batches: parse(&fields[0])?,
+                max_chunks: parse(&fields[1])?,
+                max_bytes: parse(&fields[2])?,
+            })
+        })();
+        match edit {
+            Ok(edit) => run.dispatch((action, edit)),
+            Err(e) => set_status.set(Some(Err(e.to_string()))),
+        }
+    };
+
+    view! {
+        <Header />
+        <div class="container pt-24">
+            <AdminNav />
+            <h1 class="text-xl font-semibold">"Asset canister configuration"</h1>
+            <p class="text-sm text-gray-600">{canister_id.to_text()}</p>
+
+            <Suspense fallback=|| view! { <p>"Loading configuration..."</p> }>
+                {move || match configuration.get() {
+                    Some(None) => view! { <p>"Log in to view the configuration."</p> }.into_view(),
+                    Some(Some(Ok(limits))) => {
+                        view! {
+                            <dl class="grid grid-cols-2 gap-1 mt-4 w-min text-sm whitespace-nowrap">
+                                <dt class="font-medium">"max_batches"</dt>
+                                <dd>{limit_text(limits.max_batches)}</dd>
+                                <dt class="font-medium">"max_chunks"</dt>
+                                <dd>{limit_text(limits.max_chunks)}</dd>
+                                <dt class="font-medium">"max_bytes"</dt>
+                                <dd>{limit_text(limits.max_bytes)}</dd>
+                            </dl>
+                        }
+                            .into_view()
+                    }
+                    Some(Some(Err(e))) => view! { <p class="text-red-500">{e}</p> }.into_view(),
+                    None => ().into_view(),
+                }}
+            </Suspense>
+
+            <form class="flex flex-col gap-4 mt-6" on:submit=|e| e.prevent_default()>
+                {fields
+                    .into_iter()
+                    .map(|(name, mode, value)| {
+                        view! {
+                            <label class="flex flex-col gap-1">
+                                <span class="text-sm font-medium text-gray-900">{name}</span>
+                                <div class="flex gap-2">
+                                    <EditModeSelect mode=mode />
+                                    <input
+                                        type="number"
+                                        min="0"
+                                        prop:value=move || value.get()
+                                        on:input=move |e| value.set(event_target_value(&e))
+                                        disabled=move || mode.get() != EditMode::Set
+                                        class="block w-full rounded-md border-gray-300 shadow-sm"
+                                    />
+                                </div>
+                            </label>
+                        }
+                    })
+                    .collect_view()}
+                <div class="flex gap-2">
+                    <button
+                        type="button"
+                        on:click=move |_| on_action(ConfigureAction::Validate)
+                        disabled=move || run.pending().get()
+                        class="py-2 px-4 font-semibold text-white bg-gray-500 rounded hover:bg-gray-600"
+                    >
+                        "Validate (for governed canisters)"
+                    </button>
+                    <button
+                        type="button"
+                        on:click=move |_| on_action(ConfigureAction::Apply)
+                        disabled=move || run.pending().get()
+                        class="py-2 px-4 font-semibold text-white bg-blue-500 rounded hover:bg-blue-600"
+                    >
+                        "Apply"
+                    </button>
+                </div>
+            </form>
+
+            {move || match status.get() {
+                Some(Ok(message)) => {
+                    view! { <p class="mt-4 text-sm text-green-700">{message}</p> }.into_view()
+                }
+                Some(Err(message)) => {
+                    view! { <p class="mt-4 text-sm text-red-500">{message}</p> }.into_view()
+                }
+                None => ().into_view(),
+            }}
+        </div>
+    }
+    .into_view()
+}
@@ -0,0 +1,177 @@
+use crate::components::header::Header;
+use crate::components::invalid_canister::InvalidCanister;
+use crate::components::verified_badge::VerifiedBadge;
+use crate::outbound::fetch_asset::fetch_asset;
+use crate::state::canisters::Canisters;
+use crate::utils::asset_url::asset_url;
+use crate::utils::pdf::{is_pdf, pdf_page_count};
+use crate::utils::use_asset_canister_query;
+use gloo_file::{Blob, ObjectUrl};
+use leptos::*;
+use leptos_router::*;
+use std::rc::Rc;
+
+/// Most page thumbnails rendered at once, each is a separate PDF render
+const MAX_THUMBNAILS: usize = 24;
+
+/// Original file name of a document, the last segment of its `/file/<sha>/<name>`
+/// key. Documents uploaded before keys were content-addressed use `/file-<name>`.
+fn file_name(key: &str) -> String {
+    let name = key.rsplit('/').next().unwrap_or(key);
+    name.strip_prefix("file-").unwrap_or(name).to_string()
+}
+
+/// A downloaded document, kept alive as an object URL while the page is open
+#[derive(Clone)]
+struct LoadedDocument {
+    object_url: Rc<ObjectUrl>,
+    page_count: Option<usize>,
+    is_pdf: bool,
+}
+
+/// Viewer for an uploaded document, streamed from the asset canister with
+/// `get`/`get_chunk` rather than over HTTP
+#[component]
+pub fn DocumentViewer() -> impl IntoView {
+    let params = use_params_map();
+    let key = create_memo(move |_| {
+        format!(
+            "/{}",
+            params.with(|p| p.get("key").cloned().unwrap_or_default())
+        )
+    });
+    let title = move || file_name(&key.get());
+
+    let canisters_signal = use_context::<RwSignal<Option<Rc<Canisters>>>>()
+        .expect("Canisters signal should be provided by AuthServiceProvider");
+    let canister_id = match use_asset_canister_query() {
+        Ok(canister_id) => canister_id,
+        Err(error) => return view! { <InvalidCanister error=error /> }.into_view(),
+    };
+
+    let document = create_local_resource(
+        move || (canisters_signal.get(), key.get()),
+        move |(canisters, key)| async move {
+            let canisters = canisters?;
+            Some(
+                fetch_asset(&canisters, canister_id, &key, "identity")
+                    .await
+                    .map(|asset| {
+                        let blob = Blob::new_with_options(
+                            asset.content.as_slice(),
+                            Some(&asset.content_type),
+                        );
+                        LoadedDocument {
+                            object_url: Rc::new(ObjectUrl::from(blob)),
+                            page_count: pdf_page_count(&asset.content),
+                            is_pdf: is_pdf(&asset.content),
+                        }
+                    })
+                    .map_err(|e| e.to_string()),
+            )
+        },
+    );
+
+    view! {
+        <Header />
+        <div class="container pt-24">
+            <h1 class="text-xl font-semibold">{title}</h1>
+            <div class="flex gap-4 items-center mt-2">
+                <a
+                    href=move || asset_url(Some(canister_id), &key.get())
+                    class="text-blue-500 underline"
+                    target="_blank"
+                >
+                    "Open"
+                </a>
+                {move || view! { <VerifiedBadge asset_key=key.get() canister_id=Some(canister_id) /> }}
+            </div>
+
+            <Suspense fallback=|| view! { <p class="mt-4">"Loading document..."</p> }>
+                {move || match document.get() {
+                    Some(None) => view! { <p>"Connecting to the network..."</p> }.into_view(),
+                    Some(Some(Err(e))) => view! { <p class="text-red-500">{e}</p> }.into_view(),
+                    Some(Some(Ok(document))) => {
+                        let url = document.object_url.to_string();
+                        let pages = document
+                            .page_count
+                            .map(|count| format!("{} page(s)", count))
+                            .unwrap_or_else(|| "Page count unavailable".to_string());
+                        let thumbnails = document.page_count.unwrap_or(0).min(MAX_THUMBNAILS);
+                        let is_pdf = document.is_pdf;
+                        view! {
+                            <div class="flex gap-4 items-center mt-2 text-sm">
+                                <span class="text-gray-600">{pages}</span>
+                                <a
+                                    href=url.clone()
+                                    download=title()
+                                    class="py-1 px-3 font-semibold text-white bg-blue-500 rounded hover:bg-blue-600"
+                                >
+                                    "Download"
+                                </a>
+                            </div>
+                            <Show when=move || !is_pdf>
+                                <p class="mt-2 text-sm text-gray-600">
+                                    "This document is not a PDF and can only be downloaded."
+                                </p>
+                            </Show>
+                            <Show when=move || is_pdf>
+                                <div class="flex gap-4 mt-4">
+                                    // Without a page count the viewer's own navigation is used
+                                    {(thumbnails > 0)
+                                        .then(|| {
+                                            view! {
+                                                <div class="flex overflow-y-auto flex-col gap-2 w-40 shrink-0 h-[80vh]">
+                                                    {(1..=thumbnails)
+                                                        .map(|page| {
+                                                            view! {
+                                                                <button
+                                                                    type="button"
+                                                                    class="block h-48 rounded border shrink-0"
+                                                                    on:click={
+                                                                        let url = url.clone();
+                                                                        move |_| set_page(&url, page)
+                                                                    }
+                                                                >
+                                                                    <object
+                                                                        data=format!(
+                                                                            "{}#page={}&toolbar=0&navpanes=0&view=FitH",
+                                                                            url,
+                                                                            page,
+                                                                        )
+                                                                        type="application/pdf"
+                                                                        class="w-full h-full pointer-events-none"
+                                                                    ></object>
+                                                                    <span class="text-xs">{page}</span>
+                                                                </button>
+                                                            }
+                                                        })
+                                                        .collect_view()}
+                                                </div>
+                                            }
+                                        })}
+                                    <iframe
+                                        id="document-viewer"
+                                        src=url.clone()
+                                        title=title()
+                                        class="flex-1 rounded border h-[80vh]"
+                                    ></iframe>
+                                </div>
+                            </Show>
+                        }
+                            .into_view()
+                    }
+                    None => ().into_view(),
+                }}
+            </Suspense>
+        </div>
+    }
+    .into_view()
+}
+
+/// Jump the embedded viewer to `page`
+fn set_page(url: &str, page: usize) {
+    if let Some(frame) = document().get_element_by_id("document-viewer") {
+        let _ = frame.set_attribute("src", &format!("{}#page={}", url, page));
+    }
+}
@@ -1,44 +1,87 @@
 use crate::components::header::Header;
-use crate::outbound::upload_files::upload_files_from_input_event;
-use crate::state::auth::AuthService;
-use crate::state::canisters::Canisters;
+use crate::components::image_gallery::ImageGallery;
+use crate::components::local_preview::{local_previews, LocalPreview, UploadingPreview};
+use crate::components::not_authorized::NotAuthorizedNotice;
+use crate::components::proposed_batch::ProposedBatchSummary;
+use crate::components::verified_badge::VerifiedBadge;
+use crate::consts::draft::AUTOSAVE_DEBOUNCE;
+use crate::outbound::authorization::NotAuthorized;
+use crate::outbound::batch_upload::delete_assets;
+use crate::outbound::collections::submit_collection;
+use crate::outbound::proposals::ProposedBatch;
+use crate::outbound::provision::asset_canister_for;
+use crate::outbound::upload_files::{upload_files_from_input_event, UploadField, UploadMode};
+use crate::state::auth::use_auth;
+use crate::state::canisters::{default_asset_canister, Canisters};
+use crate::state::collection::{
+    CarCollection, CollectionDocument, CollectionImage, DocumentCategory,
+};
+use crate::state::draft::{clear_draft, load_draft, save_draft, uploaded_keys};
+use crate::utils::asset_url::{asset_url, document_href};
+use crate::utils::validation::{document_flags, error_for, validate_collection, Field};
+use crate::utils::{date_input_value, now_nanos, parse_date_input};
+use anyhow::{anyhow, Error};
 use candid::Principal;
 use gloo::file::futures::read_as_bytes;
 use gloo_file::Blob;
 use leptos::logging::log;
 use leptos::*;
 use leptos::*;
-use std::cell::RefCell;
+use leptos_router::use_navigate;
+use std::collections::HashSet;
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 use web_sys::{Event, HtmlInputElement}; // Import the upload function
-/// Define the CarCollection struct
-#[derive(Clone)]
-pub struct CarCollection {
-    pub id: u64,
-    pub name: String,
-    pub model: String,
-    pub logo: String,           // Asset reference (e.g., URL or asset ID)
-    pub images: Vec<String>,    // List of asset references
-    pub documents: Vec<String>, // List of asset references
-    pub owner: Principal,
-    pub approved: bool,
+/// The collection's own asset canister, asking the provision canister for one the
+/// first time files are uploaded. An unset id (0) gets the owner's canister.
+async fn resolve_asset_canister(
+    canisters: &Canisters,
+    asset_canister: Option<Principal>,
+    collection_id: u64,
+) -> Result<Principal, Error> {
+    if let Some(canister_id) = asset_canister {
+        return Ok(canister_id);
+    }
+    let owner = canisters
+        .agent
+        .get_principal()
+        .map_err(|e| anyhow!("Failed to get principal: {}", e))?;
+    asset_canister_for(
+        canisters,
+        owner,
+        (collection_id != 0).then_some(collection_id),
+    )
+    .await
 }
 
-// Implement Default manually due to Principal not implementing Default
-impl Default for CarCollection {
-    fn default() -> Self {
-        Self {
-            id: 0,
-            name: String::new(),
-            model: String::new(),
-            logo: String::new(),
-            images: Vec::new(),
-            documents: Vec::new(),
-            owner: Principal::anonymous(),
-            approved: false,
+/// Put an uploaded key into the collection field it was uploaded for, once
+fn add_asset_key(collection: &mut CarCollection, field: UploadField, key: String) {
+    match field {
+        UploadField::Logo => collection.logo = key,
+        UploadField::Images if !collection.images.iter().any(|image| image.key == key) => {
+            collection.images.push(CollectionImage::new(key))
+        }
+        UploadField::Documents
+            if !collection
+                .documents
+                .iter()
+                .any(|document| document.key == key) =>
+        {
+            collection.documents.push(CollectionDocument::new(key))
         }
+        _ => {}
+    }
+}
+
+/// Apply `update` to the collection's document stored under `key`
+fn update_document(
+    collection: &mut CarCollection,
+    key: &str,
+    update: impl FnOnce(&mut CollectionDocument),
+) {
+    if let Some(document) = collection.documents.iter_mut().find(|d| d.key == key) {
+        update(document);
     }
 }
 
@@ -53,19 +96,15 @@ pub fn Home() -> impl IntoView {
     // Uploading state signals (use create_rw_signal)
     let uploading = create_rw_signal(false);
     let uploading_progress = create_rw_signal(0);
+    let not_authorized = create_rw_signal(None::<NotAuthorized>);
+    // Governed canisters only accept uploads proposed for a vote
+    let upload_mode = create_rw_signal(UploadMode::Direct);
+    let proposals = create_rw_signal(Vec::<ProposedBatch>::new());
+    // Selected files shown from their local copy until their upload commits
+    let previews = create_rw_signal(Vec::<LocalPreview>::new());
     // let canisters_signal = use_context::<RwSignal<Option<Rc<Canisters>>>>()
     //     .expect("Canisters signal not found in context");
-    let auth_service =
-        use_context::<Rc<RefCell<AuthService>>>().expect("AuthService context must be provided");
-
-    // Reactive signal for authentication state
-    let is_authenticated = create_memo({
-        let auth_service = Rc::clone(&auth_service);
-        move |_| auth_service.borrow().is_authenticated()
-    });
-
-    let canisters_signal = use_context::<RwSignal<Option<Rc<Canisters>>>>()
-        .expect("Canisters signal should be provided by AuthServiceProvider");
+    let auth = use_auth();
 
     if let Some(canisters_signal) = use_context::<RwSignal<Option<Rc<Canisters>>>>() {
         let canisters_option = canisters_signal.get();
@@ -81,20 +120,22 @@ pub fn Home() -> impl IntoView {
     } // Get the current value of the signal
       // Handler for file selection (upload)
 
-    let canisters_option = canisters_signal.clone();
     let on_select = {
         let set_collection = set_collection.clone();
         let uploading = uploading.clone();
         let uploading_progress = uploading_progress.clone();
         let error_message = set_error_message.clone();
 
-        move |event: Event, field: &'static str| {
+        move |event: Event, field: UploadField| {
             // Reset error message
             error_message.set(String::new());
+            not_authorized.set(None);
 
             // Set uploading state
             uploading.set(true);
             uploading_progress.set(0);
+            let selected = local_previews(&event, field);
+            previews.update(|p| p.extend(selected.iter().cloned()));
 
             // Clone variables for async task
             let set_collection = set_collection.clone();
@@ -103,49 +144,89 @@ pub fn Home() -> impl IntoView {
             let error_message = error_message.clone();
 
             spawn_local(async move {
-                match canisters_option.get() {
-                    Some(canisters) => {
-                        match upload_files_from_input_event(event.clone(), canisters).await {
-                            Ok(asset_keys) => {
-                                // Handle success
-                                match field {
-                                    "logo" => {
-                                        if let Some(asset_key) = asset_keys.first() {
-                                            set_collection.update(|c| {
-                                                c.logo = asset_key.clone();
-                                            });
-                                        }
-                                    }
-                                    "images" => {
-                                        set_collection.update(|c| {
-                                            c.images.extend(asset_keys.clone());
+                // Anonymous visitors are asked to log in once they start an upload
+                match auth.require_login().await {
+                    Ok(canisters) => {
+                        let current = collection.get_untracked();
+                        match resolve_asset_canister(&canisters, current.asset_canister, current.id)
+                            .await
+                        {
+                            Ok(canister_id) => {
+                                set_collection.update(|c| c.asset_canister = Some(canister_id));
+                                match upload_files_from_input_event(
+                                    event.clone(),
+                                    auth,
+                                    canisters,
+                                    canister_id,
+                                    field,
+                                    upload_mode.get_untracked(),
+                                    |file_name, key| {
+                                        // Switch this file over to its canister-hosted copy
+                                        previews.update(|p| {
+                                            if let Some(i) = p.iter().position(|preview| {
+                                                preview.field == field
+                                                    && preview.file_name == file_name
+                                            }) {
+                                                p.remove(i).revoke();
+                                            }
                                         });
-                                    }
-                                    "documents" => {
+                                        set_collection
+                                            .update(|c| add_asset_key(c, field, key.clone()));
+                                    },
+                                )
+                                .await
+                                {
+                                    Ok(outcome) => {
+                                        let asset_keys = outcome.asset_keys;
+                                        proposals.update(|p| p.extend(outcome.proposals));
+                                        if !outcome.warnings.is_empty() {
+                                            error_message.set(outcome.warnings.join("\n"));
+                                        }
+                                        // Handle success, keys committed directly are already in
                                         set_collection.update(|c| {
-                                            c.documents.extend(asset_keys.clone());
+                                            for asset_key in asset_keys {
+                                                add_asset_key(c, field, asset_key);
+                                            }
                                         });
+                                        uploading_progress.set(100);
                                     }
-                                    _ => {
-                                        log::warn!("Unknown field: {}", field);
-                                    }
+                                    Err(e) => match e.downcast::<NotAuthorized>() {
+                                        Ok(e) => {
+                                            log::error!("{}", e);
+                                            not_authorized.set(Some(e));
+                                        }
+                                        Err(e) => {
+                                            // Handle error
+                                            log::error!("Upload failed: {:?}", e);
+                                            error_message.set(format!("Upload failed: {:?}", e));
+                                        }
+                                    },
                                 }
-                                uploading_progress.set(100);
                             }
                             Err(e) => {
-                                // Handle error
-                                log::error!("Upload failed: {:?}", e);
-                                error_message.set(format!("Upload failed: {:?}", e));
+                                log::error!("No asset canister for this collection: {:?}", e);
+                                error_message
+                                    .set(format!("No asset canister for this collection: {}", e));
                             }
                         }
                     }
-                    None => {
-                        log::error!("Canisters not available. Please log in.");
-                        error_message.set("Canisters not available. Please log in.".to_string());
+                    Err(e) => {
+                        log::error!("Not logged in: {}", e);
+                        error_message.set(format!("Log in to upload files: {}", e));
                     }
                 }
 
                 uploading.set(false);
+                // Files that were proposed, skipped or failed have no committed copy to show
+                previews.update(|p| {
+                    p.retain(|preview| {
+                        let leftover = selected.contains(preview);
+                        if leftover {
+                            preview.revoke();
+                        }
+                        !leftover
+                    })
+                });
 
                 match event
                     .target()
@@ -161,22 +242,152 @@ pub fn Home() -> impl IntoView {
             });
         }
     };
-    // Remove image handler
-    let remove_image = {
-        let set_collection = set_collection.clone();
-        move |image_path: String| {
-            set_collection.update(move |c| {
-                c.images.retain(|p| p != &image_path);
-            });
+    // Draft autosave, kept per logged in principal so a reload loses nothing
+    let principal = auth.principal;
+    // Draft found on load, waiting for the user to restore or discard it
+    let pending_draft = create_rw_signal(None::<CarCollection>);
+    // Discarded draft whose uploaded files may still be deleted
+    let discarded_draft = create_rw_signal(None::<CarCollection>);
+    let draft_checked = create_rw_signal(false);
+    create_effect(move |previous: Option<Option<Principal>>| {
+        let current = principal.get();
+        // The form belongs to the identity that filled it in, so logging out or
+        // switching identity starts over and offers the next identity's own draft.
+        // Logging in from an anonymous session keeps what was typed.
+        if matches!(previous, Some(Some(previous)) if current != Some(previous)) {
+            draft_checked.set(false);
+            pending_draft.set(None);
+            discarded_draft.set(None);
+            set_collection.set(CarCollection::default());
+        }
+        if let Some(principal) = current {
+            if !draft_checked.get_untracked() {
+                pending_draft
+                    .set(load_draft(principal).filter(|draft| *draft != CarCollection::default()));
+                draft_checked.set(true);
+            }
+        }
+        current
+    });
+
+    let autosave = store_value(None::<TimeoutHandle>);
+    create_effect(move |_| {
+        let current = collection.get();
+        // Don't overwrite a stored draft the user hasn't decided on yet
+        if !draft_checked.get() || pending_draft.with(Option::is_some) {
+            return;
+        }
+        let Some(principal) = principal.get_untracked() else {
+            return;
+        };
+        autosave.update_value(|handle| {
+            if let Some(handle) = handle.take() {
+                handle.clear();
+            }
+        });
+        let handle = set_timeout_with_handle(
+            move || {
+                if current == CarCollection::default() {
+                    clear_draft(principal);
+                } else {
+                    save_draft(principal, &current);
+                }
+            },
+            AUTOSAVE_DEBOUNCE,
+        )
+        .ok();
+        autosave.set_value(handle);
+    });
+
+    let restore_draft = move |_| {
+        if let Some(draft) = pending_draft.get_untracked() {
+            set_collection.set(draft);
+        }
+        pending_draft.set(None);
+    };
+    let discard_draft = move |_| {
+        if let Some(principal) = principal.get_untracked() {
+            clear_draft(principal);
         }
+        discarded_draft.set(
+            pending_draft
+                .get_untracked()
+                .filter(|draft| !uploaded_keys(draft).is_empty()),
+        );
+        pending_draft.set(None);
     };
+    let cleanup = create_action(move |draft: &CarCollection| {
+        let draft = draft.clone();
+        async move {
+            let canisters = match auth.require_login().await {
+                Ok(canisters) => canisters,
+                Err(e) => {
+                    set_error_message.set(e);
+                    return;
+                }
+            };
+            let canister_id = draft.asset_canister.unwrap_or_else(default_asset_canister);
+            match delete_assets(&canisters, canister_id, uploaded_keys(&draft)).await {
+                Ok(()) => discarded_draft.set(None),
+                Err(e) => set_error_message.set(format!("Failed to delete draft files: {}", e)),
+            }
+        }
+    });
+
+    // Logo badge input, so it re-verifies after "Change logo" but not on other edits
+    let logo_asset = create_memo(move |_| collection.with(|c| (c.logo.clone(), c.asset_canister)));
+
+    // Validation; text fields only show their error once the user has left them
+    let errors = create_memo(move |_| validate_collection(&collection.get()));
+    let touched = create_rw_signal(HashSet::<Field>::new());
+    let shown_error = move |field: Field| {
+        let text_field = matches!(field, Field::Name | Field::Model);
+        if text_field && !touched.with(|t| t.contains(&field)) {
+            return None;
+        }
+        errors.with(|errors| error_for(errors, field))
+    };
+    let error_view = move |field: Field| {
+        move || {
+            shown_error(field)
+                .map(|message| view! { <p class="mt-1 text-xs text-red-500">{message}</p> })
+        }
+    };
+
+    // Submission, which clears the draft and opens the stored collection
+    let navigate = use_navigate();
+    let submit = create_action(move |current: &CarCollection| {
+        let current = current.clone();
+        let navigate = navigate.clone();
+        async move {
+            let canisters = match auth.require_login().await {
+                Ok(canisters) => canisters,
+                Err(e) => {
+                    set_error_message.set(format!("Log in to submit: {}", e));
+                    return;
+                }
+            };
+            match submit_collection(&canisters, current).await {
+                Ok(id) => {
+                    if let Some(principal) = principal.get_untracked() {
+                        clear_draft(principal);
+                    }
+                    set_collection.set(CarCollection::default());
+                    touched.set(HashSet::new());
+                    proposals.set(Vec::new());
+                    navigate(&format!("/collections/{}", id), Default::default());
+                }
+                Err(e) => set_error_message.set(format!("Submit failed: {}", e)),
+            }
+        }
+    });
 
     // Remove document handler
     let remove_document = {
         let set_collection = set_collection.clone();
         move |doc_path: String| {
             set_collection.update(move |c| {
-                c.documents.retain(|p| p != &doc_path);
+                c.documents.retain(|document| document.key != doc_path);
             });
         }
     };
@@ -207,22 +418,66 @@ pub fn Home() -> impl IntoView {
                 <form class="flex flex-col gap-4">
                     <Header />
 
-                    // ID Field
-                    <label class="block">
-                        <span class="text-sm font-medium leading-6 text-gray-900">"ID:"</span>
-                        <input
-                            type="number"
-                            value=move || collection().id.to_string()
-                            on:input=move |e| {
-                                let value = event_target_value(&e);
-                                if let Ok(id) = value.parse::<u64>() {
-                                    set_collection.update(|c| c.id = id);
+                    // Draft restore prompt
+                    <Show when=move || pending_draft.with(Option::is_some)>
+                        <div class="flex gap-4 items-center p-4 text-sm rounded border border-blue-300 bg-blue-50">
+                            <span class="flex-1">
+                                "You have an unsaved draft"
+                                {move || {
+                                    pending_draft
+                                        .get()
+                                        .filter(|draft| !draft.name.is_empty())
+                                        .map(|draft| format!(" ({})", draft.name))
+                                }} ". Restore it?"
+                            </span>
+                            <button
+                                type="button"
+                                on:click=restore_draft
+                                class="py-1 px-3 text-white bg-blue-500 rounded hover:bg-blue-600"
+                            >
+                                "Restore draft"
+                            </button>
+                            <button
+                                type="button"
+                                on:click=discard_draft
+                                class="py-1 px-3 text-gray-700 bg-white rounded border hover:bg-gray-100"
+                            >
+                                "Discard"
+                            </button>
+                        </div>
+                    </Show>
+
+                    // Cleanup of files uploaded for a discarded draft
+                    {move || {
+                        discarded_draft
+                            .get()
+                            .map(|draft| {
+                                let count = uploaded_keys(&draft).len();
+                                view! {
+                                    <div class="flex gap-4 items-center p-4 text-sm rounded border border-yellow-300 bg-yellow-50">
+                                        <span class="flex-1">
+                                            {count}
+                                            " file(s) uploaded for the discarded draft are still stored in the asset canister."
+                                        </span>
+                                        <button
+                                            type="button"
+                                            on:click=move |_| cleanup.dispatch(draft.clone())
+                                            disabled=move || cleanup.pending().get()
+                                            class="py-1 px-3 text-white bg-red-500 rounded hover:bg-red-600"
+                                        >
+                                            "Delete them"
+                                        </button>
+                                        <button
+                                            type="button"
+                                            on:click=move |_| discarded_draft.set(None)
+                                            class="py-1 px-3 text-gray-700 bg-white rounded border hover:bg-gray-100"
+                                        >
+                                            "Keep"
+                                        </button>
+                                    </div>
                                 }
-                            }
-                            class="block mt-1 w-full rounded-md border-gray-300 shadow-sm"
-                            placeholder="Enter the car collection ID"
-                        />
-                    </label>
+                            })
+                    }}
 
                     // Name Field
                     <label class="block">
@@ -234,9 +489,13 @@ pub fn Home() -> impl IntoView {
                                 let value = event_target_value(&e);
                                 set_collection.update(|c| c.name = value);
                             }
+                            on:blur=move |_| touched.update(|t| {
+                                t.insert(Field::Name);
+                            })
                             class="block mt-1 w-full rounded-md border-gray-300 shadow-sm"
                             placeholder="Enter the car collection name"
                         />
+                        {error_view(Field::Name)}
                     </label>
 
                     // Model Field
@@ -249,9 +508,40 @@ pub fn Home() -> impl IntoView {
                                 let value = event_target_value(&e);
                                 set_collection.update(|c| c.model = value);
                             }
+                            on:blur=move |_| touched.update(|t| {
+                                t.insert(Field::Model);
+                            })
                             class="block mt-1 w-full rounded-md border-gray-300 shadow-sm"
                             placeholder="Enter the car model"
                         />
+                        {error_view(Field::Model)}
+                    </label>
+
+                    // Upload Mode
+                    <label class="block">
+                        <span class="text-sm font-medium leading-6 text-gray-900">
+                            "Upload mode:"
+                        </span>
+                        <select
+                            on:change=move |e| {
+                                upload_mode
+                                    .set(
+                                        if event_target_value(&e) == "propose" {
+                                            UploadMode::Propose
+                                        } else {
+                                            UploadMode::Direct
+                                        },
+                                    )
+                            }
+                            class="block mt-1 w-full rounded-md border-gray-300 shadow-sm"
+                        >
+                            <option value="direct" selected=move || upload_mode.get() == UploadMode::Direct>
+                                "Commit directly"
+                            </option>
+                            <option value="propose" selected=move || upload_mode.get() == UploadMode::Propose>
+                                "Propose for governance (SNS controlled canister)"
+                            </option>
+                        </select>
                     </label>
 
                     // Logo Upload
@@ -277,12 +567,30 @@ pub fn Home() -> impl IntoView {
                             </div>
 
                             <input
-                                on:change=move |e| (on_select)(e, "logo")
+                                on:change=move |e| (on_select)(e, UploadField::Logo)
                                 type="file"
                                 accept="image/*"
                                 class="sr-only"
                             />
                         </label>
+                        {error_view(Field::Logo)}
+                    </div>
+
+                    // Logo being uploaded
+                    <div class="flex gap-2">
+                        <For
+                            each=move || {
+                                previews
+                                    .get()
+                                    .into_iter()
+                                    .filter(|preview| preview.field == UploadField::Logo)
+                                    .collect::<Vec<_>>()
+                            }
+                            key=|preview| preview.object_url.clone()
+                            let:preview
+                        >
+                            <UploadingPreview preview=preview />
+                        </For>
                     </div>
 
                     // Display Logo
@@ -298,49 +606,46 @@ pub fn Home() -> impl IntoView {
                                 "X"
                             </button>
                             <img
-                                src=collection().logo.clone()
+                                src=move || collection.with(|c| asset_url(c.asset_canister, &c.logo))
                                 alt="Logo"
                                 class="object-contain w-full h-full rounded-md"
                             />
+                            <div class="absolute bottom-2 left-2">
+                                {move || {
+                                    let (logo, canister_id) = logo_asset.get();
+                                    view! { <VerifiedBadge asset_key=logo canister_id=canister_id /> }
+                                }}
+                            </div>
                         </div>
                     </Show>
 
                     // Images Upload and Display
                     <span class="text-sm font-medium leading-6 text-gray-900">"Images:"</span>
                     <div class="flex overflow-hidden overflow-x-auto gap-2 items-center p-2 w-full rounded border h-[14rem]">
+                        <ImageGallery
+                            images=Signal::derive(move || collection().images)
+                            canister_id=Signal::derive(move || collection().asset_canister)
+                            cover=Signal::derive(move || collection().logo)
+                            on_change=move |images: Vec<CollectionImage>| set_collection.update(|c| c.images = images)
+                            on_set_cover=move |key: String| set_collection.update(|c| c.logo = key)
+                        />
                         <For
-                            each=move || collection().images.clone()
-                            key=|path| path.clone()
-                            let:path
-                        >
-                            move |path|
-                            {
-                                let remove_image = remove_image.clone();
-                                let path_clone = path.clone();
-                                let path_clone1 = path.clone();
-                                let path_clone2 = path.clone();
-                                let path_clone3 = path.clone();
-                                view! {
-                                    <div class="relative p-1 w-52 h-52 rounded-md border shrink-0">
-                                        <button
-                                            on:click=move |_| remove_image(path_clone1.clone())
-                                            class="flex absolute top-2 right-2 justify-center items-center w-4 h-4 bg-white rounded-full"
-                                            aria-label=move || {
-                                                format!("Remove image {}", path_clone2)
-                                            }
-                                        >
-                                            "X"
-                                        </button>
-                                        <img
-                                            src=path_clone3.clone()
-                                            class="object-contain w-full h-full rounded-md"
-                                            alt=move || format!("Image {}", path_clone3)
-                                        />
-                                    </div>
-                                }
+                            each=move || {
+                                previews
+                                    .get()
+                                    .into_iter()
+                                    .filter(|preview| preview.field == UploadField::Images)
+                                    .collect::<Vec<_>>()
                             }
+                            key=|preview| preview.object_url.clone()
+                            let:preview
+                        >
+                            <UploadingPreview preview=preview />
                         </For>
-                        <Show when=move || collection().images.is_empty()>
+                        <Show when=move || {
+                            collection().images.is_empty()
+                                && previews.with(|p| p.iter().all(|preview| preview.field != UploadField::Images))
+                        }>
                             <div class="flex flex-1 justify-center items-center text-sm">
                                 "No images added yet"
                             </div>
@@ -367,54 +672,132 @@ pub fn Home() -> impl IntoView {
                             </div>
 
                             <input
-                                on:change=move |e| (on_select)(e, "images")
+                                on:change=move |e| (on_select)(e, UploadField::Images)
                                 type="file"
                                 accept="image/*"
                                 multiple=true
                                 class="sr-only"
                             />
                         </label>
+                        {error_view(Field::Images)}
                     </div>
 
                     // Documents Upload and Display
                     <span class="text-sm font-medium leading-6 text-gray-900">"Documents:"</span>
-                    <div class="flex overflow-hidden overflow-x-auto gap-2 items-center p-2 w-full rounded border h-[14rem]">
+                    <div class="flex overflow-hidden overflow-x-auto gap-2 items-center p-2 w-full rounded border min-h-[14rem]">
                         <For
                             each=move || collection().documents.clone()
-                            key=|path| path.clone()
-                            let:path
+                            key=|document| document.key.clone()
+                            let:document
                         >
-                            move |path|
                             {
                                 let remove_document = remove_document.clone();
-                                let path_clone = path.clone();
-                                let path_clone1 = path.clone();
-                                let path_clone2 = path.clone();
+                                let key = store_value(document.key.clone());
+                                let document = create_memo(move |_| {
+                                    key.with_value(|key| {
+                                        collection
+                                            .with(|c| c.documents.iter().find(|d| &d.key == key).cloned())
+                                            .unwrap_or_default()
+                                    })
+                                });
+                                // Only a new canister re-runs the verification, not edits to the form
+                                let asset_canister = create_memo(move |_| collection.with(|c| c.asset_canister));
+                                let edit = move |update: &dyn Fn(&mut CollectionDocument)| {
+                                    key.with_value(|key| {
+                                        set_collection.update(|c| update_document(c, key, update))
+                                    })
+                                };
                                 view! {
-                                    <div class="relative p-1 w-52 h-52 rounded-md border shrink-0">
+                                    <div class="flex relative flex-col gap-1 p-2 w-60 rounded-md border shrink-0">
                                         <button
-                                            on:click=move |_| remove_document(path_clone.clone())
+                                            on:click=move |_| remove_document(key.get_value())
                                             class="flex absolute top-2 right-2 justify-center items-center w-4 h-4 bg-white rounded-full"
-                                            aria-label=move || {
-                                                format!("Remove document {}", path_clone2)
-                                            }
+                                            aria-label=move || format!("Remove document {}", key.get_value())
                                         >
                                             "X"
                                         </button>
-                                        <div class="flex justify-center items-center w-full h-full">
-                                            <a
-                                                href=path_clone1.clone()
-                                                class="text-blue-500 underline"
-                                                target="_blank"
-                                            >
-                                                {move || format!("Document {}", path_clone1)}
-                                            </a>
-                                        </div>
+                                        <a
+                                            href=move || document_href(collection().asset_canister, &key.get_value())
+                                            class="pr-6 text-sm text-blue-500 underline truncate"
+                                        >
+                                            {move || format!("Document {}", key.get_value())}
+                                        </a>
+                                        {move || {
+                                            view! {
+                                                <VerifiedBadge
+                                                    asset_key=key.get_value()
+                                                    canister_id=asset_canister.get()
+                                                />
+                                            }
+                                        }}
+                                        <select
+                                            on:change=move |e| {
+                                                if let Some(category) = DocumentCategory::from_label(&event_target_value(&e)) {
+                                                    edit(&|d| d.category = category);
+                                                }
+                                            }
+                                            class="text-xs rounded-md border-gray-300"
+                                            aria-label="Category"
+                                        >
+                                            {DocumentCategory::ALL
+                                                .into_iter()
+                                                .map(|category| {
+                                                    view! {
+                                                        <option
+                                                            value=category.label()
+                                                            selected=move || document.with(|d| d.category == category)
+                                                        >
+                                                            {category.label()}
+                                                        </option>
+                                                    }
+                                                })
+                                                .collect_view()}
+                                        </select>
+                                        <input
+                                            type="text"
+                                            prop:value=move || document.with(|d| d.issuer.clone())
+                                            on:change=move |e| {
+                                                let issuer = event_target_value(&e);
+                                                edit(&|d| d.issuer = issuer.trim().to_string());
+                                            }
+                                            class="text-xs rounded-md border-gray-300"
+                                            placeholder="Issuer"
+                                        />
+                                        <label class="flex gap-2 items-center text-xs text-gray-700">
+                                            "Expires"
+                                            <input
+                                                type="date"
+                                                prop:value=move || {
+                                                    document.with(|d| d.expires_at.map(date_input_value).unwrap_or_default())
+                                                }
+                                                on:change=move |e| {
+                                                    let expires_at = parse_date_input(&event_target_value(&e));
+                                                    edit(&|d| d.expires_at = expires_at);
+                                                }
+                                                class="flex-1 text-xs rounded-md border-gray-300"
+                                            />
+                                        </label>
                                     </div>
                                 }
                             }
                         </For>
-                        <Show when=move || collection().documents.is_empty()>
+                        <For
+                            each=move || {
+                                previews
+                                    .get()
+                                    .into_iter()
+                                    .filter(|preview| preview.field == UploadField::Documents)
+                                    .collect::<Vec<_>>()
+                            }
+                            key=|preview| preview.object_url.clone()
+                            let:preview
+                        >
+                            <UploadingPreview preview=preview />
+                        </For>
+                        <Show when=move || {
+                            collection().documents.is_empty()
+                                && previews.with(|p| p.iter().all(|preview| preview.field != UploadField::Documents))
+                        }>
                             <div class="flex flex-1 justify-center items-center text-sm">
                                 "No documents added yet"
                             </div>
@@ -441,28 +824,44 @@ pub fn Home() -> impl IntoView {
                             </div>
 
                             <input
-                                on:change=move |e| (on_select)(e, "documents")
+                                on:change=move |e| (on_select)(e, UploadField::Documents)
                                 type="file"
                                 accept="application/pdf"
                                 multiple=true
                                 class="sr-only"
                             />
                         </label>
+                        {error_view(Field::Documents)}
+                        <ul class="text-sm text-amber-700">
+                            {move || {
+                                document_flags(&collection().documents, now_nanos())
+                                    .into_iter()
+                                    .map(|flag| view! { <li>{flag.message()}</li> })
+                                    .collect_view()
+                            }}
+                        </ul>
                     </div>
 
-                    // Approved Checkbox
-                    <label class="flex items-center mt-4">
-                        <input
-                            type="checkbox"
-                            checked=move || collection().approved
-                            on:change=move |e| {
-                                let checked = event_target_checked(&e);
-                                set_collection.update(|c| c.approved = checked);
-                            }
-                            class="w-4 h-4 transition duration-150 ease-in-out form-checkbox text-primary"
-                        />
-                        <span class="ml-2 text-sm leading-5 text-gray-900">"Approved"</span>
-                    </label>
+                    // Proposed Batches
+                    <For
+                        each=move || proposals.get()
+                        key=|proposal| proposal.batch_id.to_string()
+                        let:proposal
+                    >
+                        <ProposedBatchSummary proposal=proposal />
+                    </For>
+
+                    // Approval is decided by reviewers once the collection is submitted
+                    <p class="mt-4 text-sm text-gray-600">
+                        "New collections are reviewed before they are published."
+                    </p>
+
+                    // Missing upload permission
+                    {move || {
+                        not_authorized
+                            .get()
+                            .map(|error| view! { <NotAuthorizedNotice error=error /> })
+                    }}
 
                     // Error Message
                     <Show when=move || !error_message.get().is_empty()>
@@ -472,10 +871,19 @@ pub fn Home() -> impl IntoView {
                     // Submit Button
                     <button
                         type="button"
-                        on:click=move |_| {}
-                        class="py-2 px-4 mt-4 font-semibold text-white bg-blue-500 rounded hover:bg-blue-600"
+                        on:click=move |_| {
+                            set_error_message.set(String::new());
+                            if errors.with_untracked(|errors| errors.is_empty()) {
+                                submit.dispatch(collection.get_untracked());
+                            }
+                        }
+                        disabled=move || {
+                            submit.pending().get() || uploading.get()
+                                || !errors.with(|errors| errors.is_empty())
+                        }
+                        class="py-2 px-4 mt-4 font-semibold text-white bg-blue-500 rounded hover:bg-blue-600 disabled:opacity-50"
                     >
-                        "Submit"
+                        {move || if submit.pending().get() { "Submitting..." } else { "Submit" }}
                     </button>
 
                 </form>
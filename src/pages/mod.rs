@@ -1,2 +1,10 @@
+pub mod asset_detail;
+pub mod collection;
+pub mod configuration;
+pub mod document;
 pub mod home;
 pub mod not_found;
+pub mod ownership;
+pub mod permissions;
+pub mod proposals;
+pub mod reviews;
@@ -0,0 +1,218 @@
+use crate::components::admin_nav::AdminNav;
+use crate::components::header::Header;
+use crate::components::invalid_canister::InvalidCanister;
+use crate::components::permission_summary::PermissionSummary;
+use crate::outbound::ownership::{hand_over, take_ownership, validate_take_ownership};
+use crate::state::auth::use_auth;
+use crate::utils::use_asset_canister_query;
+use candid::Principal;
+use leptos::*;
+
+/// Where the transfer currently stands
+#[derive(Clone, PartialEq)]
+enum TransferStep {
+    NotValidated,
+    Validated(String),
+    Rejected(String),
+    Done(String),
+    Failed(String),
+}
+
+/// Admin flow for `take_ownership`, optionally handing the canister to a new owner
+#[component]
+pub fn Ownership() -> impl IntoView {
+    let auth = use_auth();
+
+    let queried = match use_asset_canister_query() {
+        Ok(queried) => queried,
+        Err(error) => return view! { <InvalidCanister error=error /> }.into_view(),
+    };
+    let (canister_text, set_canister_text) = create_signal(queried.to_text());
+    let canister_id = create_memo(move |_| Principal::from_text(canister_text.get().trim()).ok());
+    let (new_owner_text, set_new_owner_text) = create_signal(String::new());
+    let (acknowledged, set_acknowledged) = create_signal(false);
+    let (step, set_step) = create_signal(TransferStep::NotValidated);
+    let (reload, set_reload) = create_signal(0);
+
+    // Any change to the target invalidates an earlier validation
+    create_effect(move |_| {
+        canister_text.track();
+        new_owner_text.track();
+        set_step.set(TransferStep::NotValidated);
+        set_acknowledged.set(false);
+    });
+
+    let validate = create_action(move |_: &()| async move {
+        let Some(canister_id) = canister_id.get_untracked() else {
+            set_step.set(TransferStep::Rejected(
+                "Enter a valid canister id".to_string(),
+            ));
+            return;
+        };
+        let canisters = match auth.require_login().await {
+            Ok(canisters) => canisters,
+            Err(e) => {
+                set_step.set(TransferStep::Rejected(e));
+                return;
+            }
+        };
+        set_step.set(
+            match validate_take_ownership(&canisters, canister_id).await {
+                Ok(Ok(message)) => TransferStep::Validated(message),
+                Ok(Err(message)) => TransferStep::Rejected(message),
+                Err(e) => TransferStep::Rejected(e.to_string()),
+            },
+        );
+    });
+
+    let transfer = create_action(move |_: &()| async move {
+        let Some(canister_id) = canister_id.get_untracked() else {
+            return;
+        };
+        let canisters = match auth.require_login().await {
+            Ok(canisters) => canisters,
+            Err(e) => {
+                set_step.set(TransferStep::Failed(e));
+                return;
+            }
+        };
+        let new_owner = new_owner_text.get_untracked();
+        let new_owner = new_owner.trim();
+        let new_owner = if new_owner.is_empty() {
+            None
+        } else {
+            match Principal::from_text(new_owner) {
+                Ok(principal) => Some(principal),
+                Err(e) => {
+                    set_step.set(TransferStep::Failed(format!("Invalid new owner: {}", e)));
+                    return;
+                }
+            }
+        };
+
+        let result = async {
+            take_ownership(&canisters, canister_id).await?;
+            if let Some(new_owner) = new_owner {
+                hand_over(&canisters, canister_id, new_owner).await?;
+            }
+            Ok::<_, anyhow::Error>(())
+        }
+        .await;
+        set_step.set(match result {
+            Ok(()) => TransferStep::Done(match new_owner {
+                Some(new_owner) => format!("{} now belongs to {}", canister_id, new_owner),
+                None => format!("You are now the only principal on {}", canister_id),
+            }),
+            Err(e) => TransferStep::Failed(e.to_string()),
+        });
+        set_reload.update(|r| *r += 1);
+    });
+
+    let can_transfer = move || {
+        matches!(step.get(), TransferStep::Validated(_))
+            && acknowledged.get()
+            && !transfer.pending().get()
+    };
+
+    view! {
+        <Header />
+        <div class="container pt-24">
+            <AdminNav />
+            <h1 class="text-xl font-semibold">"Transfer asset canister ownership"</h1>
+
+            <div class="p-4 mt-4 text-sm rounded border border-yellow-300 bg-yellow-50">
+                <p class="font-medium">"What take_ownership does"</p>
+                <ul class="list-disc list-inside">
+                    <li>"Revokes Prepare, Commit and ManagePermissions from every principal."</li>
+                    <li>"Grants all three permissions to you, the caller. Only a controller may call it."</li>
+                    <li>
+                        "With a new owner set, all permissions are then granted to that principal and yours are revoked, so you lose access to the canister."
+                    </li>
+                    <li>"Controllers are not changed; hand those over with dfx or the NNS dapp."</li>
+                </ul>
+            </div>
+
+            <form class="flex flex-col gap-4 mt-4" on:submit=|e| e.prevent_default()>
+                <label class="flex flex-col">
+                    <span class="text-sm font-medium text-gray-900">"Asset canister"</span>
+                    <input
+                        type="text"
+                        prop:value=move || canister_text.get()
+                        on:input=move |e| set_canister_text.set(event_target_value(&e))
+                        class="block w-full rounded-md border-gray-300 shadow-sm"
+                    />
+                </label>
+                <label class="flex flex-col">
+                    <span class="text-sm font-medium text-gray-900">
+                        "New owner principal (car owner), optional"
+                    </span>
+                    <input
+                        type="text"
+                        prop:value=move || new_owner_text.get()
+                        on:input=move |e| set_new_owner_text.set(event_target_value(&e))
+                        class="block w-full rounded-md border-gray-300 shadow-sm"
+                    />
+                </label>
+
+                <button
+                    type="button"
+                    on:click=move |_| validate.dispatch(())
+                    disabled=move || validate.pending().get() || canister_id.get().is_none()
+                    class="py-2 px-4 font-semibold text-white bg-gray-500 rounded hover:bg-gray-600"
+                >
+                    "1. Validate"
+                </button>
+
+                <label class="flex items-center">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || acknowledged.get()
+                        on:change=move |e| set_acknowledged.set(event_target_checked(&e))
+                        disabled=move || !matches!(step.get(), TransferStep::Validated(_))
+                        class="w-4 h-4 form-checkbox"
+                    />
+                    <span class="ml-2 text-sm">
+                        "I understand that every other principal loses its permissions"
+                    </span>
+                </label>
+
+                <button
+                    type="button"
+                    on:click=move |_| transfer.dispatch(())
+                    disabled=move || !can_transfer()
+                    class="py-2 px-4 font-semibold text-white bg-red-500 rounded hover:bg-red-600"
+                >
+                    {move || if transfer.pending().get() { "Transferring..." } else { "2. Take ownership" }}
+                </button>
+            </form>
+
+            {move || match step.get() {
+                TransferStep::NotValidated => ().into_view(),
+                TransferStep::Validated(message) => {
+                    view! { <p class="mt-4 text-sm text-green-700">"Validation passed: " {message}</p> }
+                        .into_view()
+                }
+                TransferStep::Rejected(message) => {
+                    view! { <p class="mt-4 text-sm text-red-500">"Validation failed: " {message}</p> }
+                        .into_view()
+                }
+                TransferStep::Done(message) => {
+                    view! { <p class="mt-4 text-sm text-green-700">{message}</p> }.into_view()
+                }
+                TransferStep::Failed(message) => {
+                    view! { <p class="mt-4 text-sm text-red-500">{message}</p> }.into_view()
+                }
+            }}
+
+            <h2 class="mt-6 font-medium">"Current permissions"</h2>
+            {move || {
+                canister_id
+                    .get()
+                    .map(|canister_id| {
+                        view! { <PermissionSummary canister_id=canister_id reload=reload /> }
+                    })
+            }}
+        </div>
+    }
+    .into_view()
+}
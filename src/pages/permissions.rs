@@ -0,0 +1,282 @@
+use crate::canister::asset_proxy::Permission;
+use crate::components::admin_nav::AdminNav;
+use crate::components::header::Header;
+use crate::components::invalid_canister::InvalidCanister;
+use crate::outbound::permissions::{
+    all_permissions, authorize, deauthorize, grant_permission, list_authorized, list_permitted,
+    permission_from_name, permission_name, revoke_permission,
+};
+use crate::state::auth::use_auth;
+use crate::state::canisters::Canisters;
+use crate::utils::use_asset_canister_query;
+use candid::Principal;
+use leptos::*;
+use std::rc::Rc;
+
+/// Name of the list and picker entry for principals added with the legacy `authorize` call
+const LEGACY_AUTHORIZED: &str = "Authorized (legacy)";
+
+/// Principals holding one permission, or the error returned while listing them
+#[derive(Clone)]
+struct PermissionList {
+    name: &'static str,
+    principals: Result<Vec<Principal>, String>,
+}
+
+/// Outcome of a grant or revoke call, shown in the operations log
+#[derive(Clone)]
+struct OperationResult {
+    description: String,
+    result: Result<(), String>,
+}
+
+#[derive(Clone)]
+enum PermissionChange {
+    Grant(Principal, Permission),
+    Revoke(Principal, Permission),
+    Authorize(Principal),
+    Deauthorize(Principal),
+}
+
+/// Admin page listing and editing the principals permitted on the asset canister
+#[component]
+pub fn Permissions() -> impl IntoView {
+    let canisters_signal = use_context::<RwSignal<Option<Rc<Canisters>>>>()
+        .expect("Canisters signal should be provided by AuthServiceProvider");
+    let auth = use_auth();
+    let canister_id = match use_asset_canister_query() {
+        Ok(canister_id) => canister_id,
+        Err(error) => return view! { <InvalidCanister error=error /> }.into_view(),
+    };
+
+    let (reload, set_reload) = create_signal(0);
+    let permission_lists = create_local_resource(
+        move || (canisters_signal.get(), reload.get()),
+        move |(canisters, _)| async move {
+            let canisters = canisters?;
+            let mut lists = Vec::new();
+            for permission in all_permissions() {
+                let name = permission_name(&permission);
+                let principals = list_permitted(&canisters, canister_id, permission)
+                    .await
+                    .map_err(|e| e.to_string());
+                lists.push(PermissionList { name, principals });
+            }
+            lists.push(PermissionList {
+                name: LEGACY_AUTHORIZED,
+                principals: list_authorized(&canisters, canister_id)
+                    .await
+                    .map_err(|e| e.to_string()),
+            });
+            Some(lists)
+        },
+    );
+
+    let (new_principal, set_new_principal) = create_signal(String::new());
+    let (new_permission, set_new_permission) = create_signal("Commit".to_string());
+    let (form_error, set_form_error) = create_signal(String::new());
+    let (operations, set_operations) = create_signal(Vec::<OperationResult>::new());
+    let change = create_action(move |change: &PermissionChange| {
+        let change = change.clone();
+        async move {
+            let canisters = match auth.require_login().await {
+                Ok(canisters) => canisters,
+                Err(e) => {
+                    set_form_error.set(e);
+                    return;
+                }
+            };
+            let (description, result) = match change {
+                PermissionChange::Grant(principal, permission) => (
+                    format!("Grant {} to {}", permission_name(&permission), principal),
+                    grant_permission(&canisters, canister_id, principal, permission).await,
+                ),
+                PermissionChange::Revoke(principal, permission) => (
+                    format!("Revoke {} of {}", permission_name(&permission), principal),
+                    revoke_permission(&canisters, canister_id, principal, permission).await,
+                ),
+                PermissionChange::Authorize(principal) => (
+                    format!("Authorize {}", principal),
+                    authorize(&canisters, canister_id, principal).await,
+                ),
+                PermissionChange::Deauthorize(principal) => (
+                    format!("Deauthorize {}", principal),
+                    deauthorize(&canisters, canister_id, principal).await,
+                ),
+            };
+            set_operations.update(|ops| {
+                ops.insert(
+                    0,
+                    OperationResult {
+                        description,
+                        result: result.map_err(|e| e.to_string()),
+                    },
+                )
+            });
+            set_reload.update(|r| *r += 1);
+        }
+    });
+
+    let on_grant = move |_| {
+        set_form_error.set(String::new());
+        let principal = match Principal::from_text(new_principal.get_untracked().trim()) {
+            Ok(principal) => principal,
+            Err(e) => {
+                set_form_error.set(format!("Invalid principal: {}", e));
+                return;
+            }
+        };
+        let name = new_permission.get_untracked();
+        match permission_from_name(&name) {
+            Some(permission) => change.dispatch(PermissionChange::Grant(principal, permission)),
+            None if name == LEGACY_AUTHORIZED => {
+                change.dispatch(PermissionChange::Authorize(principal))
+            }
+            None => set_form_error.set("Unknown permission".to_string()),
+        }
+    };
+
+    view! {
+        <Header />
+        <div class="container pt-24">
+            <AdminNav />
+            <h1 class="text-xl font-semibold">"Asset canister permissions"</h1>
+            <p class="text-sm text-gray-600">{canister_id.to_text()}</p>
+
+            <Suspense fallback=|| view! { <p>"Loading permissions..."</p> }>
+                {move || match permission_lists.get() {
+                    Some(None) => view! { <p>"Log in to manage permissions."</p> }.into_view(),
+                    Some(Some(lists)) => {
+                        lists
+                            .into_iter()
+                            .map(|list| {
+                                let name = list.name;
+                                let body = match list.principals {
+                                    Ok(principals) if principals.is_empty() => {
+                                        view! { <p class="text-sm">"No principals"</p> }.into_view()
+                                    }
+                                    Ok(principals) => {
+                                        principals
+                                            .into_iter()
+                                            .map(|principal| {
+                                                view! {
+                                                    <li class="flex gap-4 items-center">
+                                                        <code class="text-sm">{principal.to_text()}</code>
+                                                        {permission_from_name(name)
+                                                            .map(|permission| {
+                                                                view! {
+                                                                    <button
+                                                                        type="button"
+                                                                        on:click=move |_| {
+                                                                            change
+                                                                                .dispatch(
+                                                                                    PermissionChange::Revoke(principal, permission.clone()),
+                                                                                )
+                                                                        }
+                                                                        class="py-1 px-2 text-xs text-white bg-red-500 rounded"
+                                                                    >
+                                                                        "Revoke"
+                                                                    </button>
+                                                                }
+                                                            })}
+                                                        {(name == LEGACY_AUTHORIZED)
+                                                            .then(|| {
+                                                                view! {
+                                                                    <button
+                                                                        type="button"
+                                                                        on:click=move |_| {
+                                                                            change.dispatch(PermissionChange::Deauthorize(principal))
+                                                                        }
+                                                                        class="py-1 px-2 text-xs text-white bg-red-500 rounded"
+                                                                    >
+                                                                        "Deauthorize"
+                                                                    </button>
+                                                                }
+                                                            })}
+                                                    </li>
+                                                }
+                                            })
+                                            .collect_view()
+                                    }
+                                    Err(e) => view! { <p class="text-sm text-red-500">{e}</p> }.into_view(),
+                                };
+                                view! {
+                                    <section class="mt-4">
+                                        <h2 class="font-medium">{name}</h2>
+                                        <ul class="flex flex-col gap-1 mt-1">{body}</ul>
+                                    </section>
+                                }
+                            })
+                            .collect_view()
+                    }
+                    None => ().into_view(),
+                }}
+            </Suspense>
+
+            <form class="flex gap-2 items-end mt-6" on:submit=|e| e.prevent_default()>
+                <label class="flex flex-col flex-1">
+                    <span class="text-sm font-medium text-gray-900">"Principal"</span>
+                    <input
+                        type="text"
+                        prop:value=move || new_principal.get()
+                        on:input=move |e| set_new_principal.set(event_target_value(&e))
+                        class="block w-full rounded-md border-gray-300 shadow-sm"
+                        placeholder="aaaaa-aa"
+                    />
+                </label>
+                <select
+                    on:change=move |e| set_new_permission.set(event_target_value(&e))
+                    class="rounded-md border-gray-300"
+                >
+                    {all_permissions()
+                        .iter()
+                        .map(|permission| {
+                            let name = permission_name(permission);
+                            view! {
+                                <option value=name selected=move || new_permission.get() == name>
+                                    {name}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                    <option
+                        value=LEGACY_AUTHORIZED
+                        selected=move || new_permission.get() == LEGACY_AUTHORIZED
+                    >
+                        {LEGACY_AUTHORIZED}
+                    </option>
+                </select>
+                <button
+                    type="button"
+                    on:click=on_grant
+                    disabled=move || change.pending().get()
+                    class="py-2 px-4 font-semibold text-white bg-blue-500 rounded hover:bg-blue-600"
+                >
+                    "Grant"
+                </button>
+            </form>
+            <Show when=move || !form_error.get().is_empty()>
+                <div class="mt-2 text-sm text-red-500">{move || form_error.get()}</div>
+            </Show>
+
+            <h2 class="mt-6 font-medium">"Operations"</h2>
+            <ul class="flex flex-col gap-1 mt-1 text-sm">
+                {move || {
+                    operations
+                        .get()
+                        .into_iter()
+                        .map(|op| match op.result {
+                            Ok(()) => {
+                                view! {
+                                    <li class="text-green-700">{op.description} ": ok"</li>
+                                }
+                            }
+                            Err(e) => view! { <li class="text-red-500">{e}</li> },
+                        })
+                        .collect_view()
+                }}
+            </ul>
+        </div>
+    }
+    .into_view()
+}
@@ -0,0 +1,154 @@
+use crate::components::admin_nav::AdminNav;
+use crate::components::header::Header;
+use crate::components::invalid_canister::InvalidCanister;
+use crate::outbound::proposals::{commit_proposed_batch, delete_batch, validate_proposed_batch};
+use crate::state::auth::use_auth;
+use crate::utils::{from_hex, use_asset_canister_query};
+use candid::Nat;
+use leptos::*;
+use leptos_router::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum ProposalAction {
+    Validate,
+    Commit,
+    Delete,
+}
+
+/// Status of a batch proposed for commit on a governed asset canister
+#[component]
+pub fn Proposals() -> impl IntoView {
+    let query = use_query_map();
+    let auth = use_auth();
+    let canister_id = match use_asset_canister_query() {
+        Ok(canister_id) => canister_id,
+        Err(error) => return view! { <InvalidCanister error=error /> }.into_view(),
+    };
+
+    let batch_id =
+        create_rw_signal(query.with_untracked(|q| q.get("batch_id").cloned().unwrap_or_default()));
+    let evidence =
+        create_rw_signal(query.with_untracked(|q| q.get("evidence").cloned().unwrap_or_default()));
+    let (status, set_status) = create_signal(None::<Result<String, String>>);
+
+    let run = create_action(move |action: &ProposalAction| {
+        let action = *action;
+        async move {
+            let canisters = match auth.require_login().await {
+                Ok(canisters) => canisters,
+                Err(e) => {
+                    set_status.set(Some(Err(e)));
+                    return;
+                }
+            };
+            let batch = match batch_id.get_untracked().trim().parse::<Nat>() {
+                Ok(batch) => batch,
+                Err(e) => {
+                    set_status.set(Some(Err(format!("Invalid batch id: {}", e))));
+                    return;
+                }
+            };
+            let evidence = match from_hex(&evidence.get_untracked()) {
+                Ok(evidence) => evidence,
+                Err(e) if action != ProposalAction::Delete => {
+                    set_status.set(Some(Err(format!("Invalid evidence: {}", e))));
+                    return;
+                }
+                Err(_) => Vec::new(),
+            };
+
+            let result = match action {
+                ProposalAction::Validate => {
+                    match validate_proposed_batch(&canisters, canister_id, batch, evidence).await {
+                        Ok(Ok(message)) => Ok(format!("Pending and valid: {}", message)),
+                        Ok(Err(message)) => Err(format!(
+                            "Not committable (already committed, deleted or evidence mismatch): {}",
+                            message
+                        )),
+                        Err(e) => Err(e.to_string()),
+                    }
+                }
+                ProposalAction::Commit => {
+                    commit_proposed_batch(&canisters, canister_id, batch.clone(), evidence)
+                        .await
+                        .map(|()| format!("Batch {} committed", batch))
+                        .map_err(|e| e.to_string())
+                }
+                ProposalAction::Delete => delete_batch(&canisters, canister_id, batch.clone())
+                    .await
+                    .map(|()| format!("Batch {} deleted", batch))
+                    .map_err(|e| e.to_string()),
+            };
+            set_status.set(Some(result));
+        }
+    });
+
+    view! {
+        <Header />
+        <div class="container pt-24">
+            <AdminNav />
+            <h1 class="text-xl font-semibold">"Proposed batches"</h1>
+            <p class="text-sm text-gray-600">
+                "Check a batch with validate_commit_proposed_batch, then commit it once the governance proposal is adopted."
+            </p>
+
+            <form class="flex flex-col gap-4 mt-4" on:submit=|e| e.prevent_default()>
+                <label class="flex flex-col">
+                    <span class="text-sm font-medium text-gray-900">"Batch id"</span>
+                    <input
+                        type="text"
+                        prop:value=move || batch_id.get()
+                        on:input=move |e| batch_id.set(event_target_value(&e))
+                        class="block w-full rounded-md border-gray-300 shadow-sm"
+                    />
+                </label>
+                <label class="flex flex-col">
+                    <span class="text-sm font-medium text-gray-900">"Evidence (hex)"</span>
+                    <input
+                        type="text"
+                        prop:value=move || evidence.get()
+                        on:input=move |e| evidence.set(event_target_value(&e))
+                        class="block w-full font-mono rounded-md border-gray-300 shadow-sm"
+                    />
+                </label>
+                <div class="flex gap-2">
+                    <button
+                        type="button"
+                        on:click=move |_| run.dispatch(ProposalAction::Validate)
+                        disabled=move || run.pending().get()
+                        class="py-2 px-4 font-semibold text-white bg-blue-500 rounded hover:bg-blue-600"
+                    >
+                        "Check status"
+                    </button>
+                    <button
+                        type="button"
+                        on:click=move |_| run.dispatch(ProposalAction::Commit)
+                        disabled=move || run.pending().get()
+                        class="py-2 px-4 font-semibold text-white bg-green-600 rounded hover:bg-green-700"
+                    >
+                        "Commit proposed batch"
+                    </button>
+                    <button
+                        type="button"
+                        on:click=move |_| run.dispatch(ProposalAction::Delete)
+                        disabled=move || run.pending().get()
+                        class="py-2 px-4 font-semibold text-white bg-red-500 rounded hover:bg-red-600"
+                    >
+                        "Delete batch"
+                    </button>
+                </div>
+            </form>
+
+            {move || match status.get() {
+                Some(Ok(message)) => {
+                    view! { <p class="mt-4 text-sm text-green-700">{message}</p> }.into_view()
+                }
+                Some(Err(message)) => {
+                    view! { <p class="mt-4 text-sm text-red-500">{message}</p> }.into_view()
+                }
+                None => ().into_view(),
+            }}
+        </div>
+    }
+    .into_view()
+}
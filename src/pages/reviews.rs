@@ -0,0 +1,346 @@
+use crate::canister::provision::{CarCollection, ReviewDecision};
+use crate::components::admin_nav::AdminNav;
+use crate::components::header::Header;
+use crate::outbound::review::{
+    add_reviewer, decision_text, is_reviewer, list_pending_collections, list_reviewers,
+    remove_reviewer, review_collection, review_history,
+};
+use crate::state::auth::use_auth;
+use crate::state::canisters::Canisters;
+use crate::state::collection::CollectionDocument;
+use crate::utils::validation::document_flags;
+use crate::utils::{format_timestamp, now_nanos};
+use candid::Principal;
+use leptos::*;
+use std::rc::Rc;
+
+#[derive(Clone)]
+enum ReviewerChange {
+    Add(Principal),
+    Remove(Principal),
+}
+
+/// Decisions taken on one collection, oldest first
+#[component]
+fn ReviewHistory(collection_id: u64, #[prop(into)] reload: MaybeSignal<i32>) -> impl IntoView {
+    let canisters_signal = use_context::<RwSignal<Option<Rc<Canisters>>>>()
+        .expect("Canisters signal should be provided by AuthServiceProvider");
+    let history = create_local_resource(
+        move || (canisters_signal.get(), reload.get()),
+        move |(canisters, _)| async move {
+            let canisters = canisters?;
+            Some(
+                review_history(&canisters, collection_id)
+                    .await
+                    .map_err(|e| e.to_string()),
+            )
+        },
+    );
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || match history.get() {
+                Some(Some(Ok(events))) if events.is_empty() => {
+                    view! { <p class="text-xs text-gray-500">"No decisions yet"</p> }.into_view()
+                }
+                Some(Some(Ok(events))) => {
+                    view! {
+                        <ul class="text-xs text-gray-600">
+                            {events
+                                .into_iter()
+                                .map(|event| {
+                                    view! {
+                                        <li>
+                                            {format_timestamp(event.timestamp)} " "
+                                            <code>{event.reviewer.to_text()}</code> ": "
+                                            {decision_text(&event.decision)}
+                                        </li>
+                                    }
+                                })
+                                .collect_view()}
+                        </ul>
+                    }
+                        .into_view()
+                }
+                Some(Some(Err(e))) => view! { <p class="text-xs text-red-500">{e}</p> }.into_view(),
+                _ => ().into_view(),
+            }}
+        </Suspense>
+    }
+}
+
+/// A pending collection with approve and reject-with-reason controls
+#[component]
+fn PendingCollection(
+    collection: CarCollection,
+    review: Action<(u64, ReviewDecision), ()>,
+    #[prop(into)] reload: MaybeSignal<i32>,
+) -> impl IntoView {
+    let id = collection.id;
+    let documents = collection
+        .documents
+        .iter()
+        .cloned()
+        .map(CollectionDocument::from)
+        .collect::<Vec<_>>();
+    let (reason, set_reason) = create_signal(String::new());
+
+    view! {
+        <li class="p-4 rounded border">
+            <div class="flex gap-4 items-baseline">
+                <span class="font-medium">{collection.name}</span>
+                <span class="text-sm text-gray-600">{collection.model}</span>
+                <span class="text-xs text-gray-500">"#" {id}</span>
+                <a href=format!("/collections/{}", id) class="text-sm text-blue-500 underline">
+                    "Open collection"
+                </a>
+            </div>
+            <p class="text-xs text-gray-500">
+                "Submitted by " <code>{collection.owner.to_text()}</code> ", "
+                {collection.images.len()} " image(s), " {collection.documents.len()}
+                " document(s)"
+            </p>
+            <ul class="text-xs text-amber-700">
+                {document_flags(&documents, now_nanos())
+                    .into_iter()
+                    .map(|flag| view! { <li>{flag.message()}</li> })
+                    .collect_view()}
+            </ul>
+            <div class="flex gap-2 items-center mt-2">
+                <button
+                    type="button"
+                    on:click=move |_| review.dispatch((id, ReviewDecision::Approve))
+                    disabled=move || review.pending().get()
+                    class="py-1 px-3 text-sm text-white bg-green-600 rounded hover:bg-green-700"
+                >
+                    "Approve"
+                </button>
+                <input
+                    type="text"
+                    prop:value=move || reason.get()
+                    on:input=move |e| set_reason.set(event_target_value(&e))
+                    class="flex-1 text-sm rounded-md border-gray-300"
+                    placeholder="Reason for rejecting"
+                />
+                <button
+                    type="button"
+                    on:click=move |_| {
+                        review.dispatch((id, ReviewDecision::Reject(reason.get_untracked().trim().to_string())))
+                    }
+                    disabled=move || review.pending().get() || reason.get().trim().is_empty()
+                    class="py-1 px-3 text-sm text-white bg-red-500 rounded hover:bg-red-600"
+                >
+                    "Reject"
+                </button>
+            </div>
+            <div class="mt-2">
+                <ReviewHistory collection_id=id reload=reload />
+            </div>
+        </li>
+    }
+}
+
+/// Reviewer management and the queue of collections waiting for approval
+#[component]
+pub fn Reviews() -> impl IntoView {
+    let canisters_signal = use_context::<RwSignal<Option<Rc<Canisters>>>>()
+        .expect("Canisters signal should be provided by AuthServiceProvider");
+    let auth = use_auth();
+
+    let (reload, set_reload) = create_signal(0);
+    let reviewers = create_local_resource(
+        move || (canisters_signal.get(), reload.get()),
+        move |(canisters, _)| async move {
+            let canisters = canisters?;
+            Some(list_reviewers(&canisters).await.map_err(|e| e.to_string()))
+        },
+    );
+    let can_review = create_local_resource(
+        move || canisters_signal.get(),
+        move |canisters| async move {
+            match canisters {
+                Some(canisters) => is_reviewer(&canisters).await.unwrap_or(false),
+                None => false,
+            }
+        },
+    );
+    let pending = create_local_resource(
+        move || (canisters_signal.get(), reload.get()),
+        move |(canisters, _)| async move {
+            let canisters = canisters?;
+            Some(
+                list_pending_collections(&canisters)
+                    .await
+                    .map_err(|e| e.to_string()),
+            )
+        },
+    );
+
+    let (status, set_status) = create_signal(None::<Result<String, String>>);
+    let review = create_action(move |(id, decision): &(u64, ReviewDecision)| {
+        let (id, decision) = (*id, decision.clone());
+        async move {
+            let canisters = match auth.require_login().await {
+                Ok(canisters) => canisters,
+                Err(e) => {
+                    set_status.set(Some(Err(e)));
+                    return;
+                }
+            };
+            let description = format!("Collection {} {}", id, decision_text(&decision));
+            set_status.set(Some(
+                review_collection(&canisters, id, decision)
+                    .await
+                    .map(|()| description)
+                    .map_err(|e| e.to_string()),
+            ));
+            set_reload.update(|r| *r += 1);
+        }
+    });
+
+    let change = create_action(move |change: &ReviewerChange| {
+        let change = change.clone();
+        async move {
+            let canisters = match auth.require_login().await {
+                Ok(canisters) => canisters,
+                Err(e) => {
+                    set_status.set(Some(Err(e)));
+                    return;
+                }
+            };
+            let result = match change {
+                ReviewerChange::Add(principal) => add_reviewer(&canisters, principal)
+                    .await
+                    .map(|()| format!("Added reviewer {}", principal)),
+                ReviewerChange::Remove(principal) => remove_reviewer(&canisters, principal)
+                    .await
+                    .map(|()| format!("Removed reviewer {}", principal)),
+            };
+            set_status.set(Some(result.map_err(|e| e.to_string())));
+            set_reload.update(|r| *r += 1);
+        }
+    });
+
+    let (new_reviewer, set_new_reviewer) = create_signal(String::new());
+    let on_add = move |_| match Principal::from_text(new_reviewer.get_untracked().trim()) {
+        Ok(principal) => change.dispatch(ReviewerChange::Add(principal)),
+        Err(e) => set_status.set(Some(Err(format!("Invalid principal: {}", e)))),
+    };
+
+    view! {
+        <Header />
+        <div class="container pt-24">
+            <AdminNav />
+            <h1 class="text-xl font-semibold">"Collection reviews"</h1>
+
+            <h2 class="mt-4 font-medium">"Reviewers"</h2>
+            <Suspense fallback=|| view! { <p>"Loading reviewers..."</p> }>
+                {move || match reviewers.get() {
+                    Some(None) => view! { <p>"Log in to manage reviews."</p> }.into_view(),
+                    Some(Some(Ok(principals))) if principals.is_empty() => {
+                        view! { <p class="text-sm">"No reviewers"</p> }.into_view()
+                    }
+                    Some(Some(Ok(principals))) => {
+                        view! {
+                            <ul class="flex flex-col gap-1 mt-1">
+                                {principals
+                                    .into_iter()
+                                    .map(|principal| {
+                                        view! {
+                                            <li class="flex gap-4 items-center">
+                                                <code class="text-sm">{principal.to_text()}</code>
+                                                <button
+                                                    type="button"
+                                                    on:click=move |_| {
+                                                        let confirmed = window()
+                                                            .confirm_with_message(
+                                                                &format!("Remove reviewer {}?", principal),
+                                                            )
+                                                            .unwrap_or(false);
+                                                        if confirmed {
+                                                            change.dispatch(ReviewerChange::Remove(principal))
+                                                        }
+                                                    }
+                                                    class="py-1 px-2 text-xs text-white bg-red-500 rounded"
+                                                >
+                                                    "Remove"
+                                                </button>
+                                            </li>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </ul>
+                        }
+                            .into_view()
+                    }
+                    Some(Some(Err(e))) => view! { <p class="text-sm text-red-500">{e}</p> }.into_view(),
+                    None => ().into_view(),
+                }}
+            </Suspense>
+            <form class="flex gap-2 items-end mt-2" on:submit=|e| e.prevent_default()>
+                <label class="flex flex-col flex-1">
+                    <span class="text-sm font-medium text-gray-900">"Reviewer principal"</span>
+                    <input
+                        type="text"
+                        prop:value=move || new_reviewer.get()
+                        on:input=move |e| set_new_reviewer.set(event_target_value(&e))
+                        class="block w-full rounded-md border-gray-300 shadow-sm"
+                    />
+                </label>
+                <button
+                    type="button"
+                    on:click=on_add
+                    disabled=move || change.pending().get()
+                    class="py-2 px-4 font-semibold text-white bg-blue-500 rounded hover:bg-blue-600"
+                >
+                    "Add reviewer"
+                </button>
+            </form>
+
+            {move || match status.get() {
+                Some(Ok(message)) => {
+                    view! { <p class="mt-4 text-sm text-green-700">{message}</p> }.into_view()
+                }
+                Some(Err(message)) => {
+                    view! { <p class="mt-4 text-sm text-red-500">{message}</p> }.into_view()
+                }
+                None => ().into_view(),
+            }}
+
+            <h2 class="mt-6 font-medium">"Pending collections"</h2>
+            <Show when=move || can_review.get() == Some(false)>
+                <p class="text-sm text-gray-600">
+                    "You are not a reviewer; approving or rejecting will be refused."
+                </p>
+            </Show>
+            <Suspense fallback=|| view! { <p>"Loading pending collections..."</p> }>
+                {move || match pending.get() {
+                    Some(Some(Ok(collections))) if collections.is_empty() => {
+                        view! { <p class="text-sm">"Nothing waiting for review"</p> }.into_view()
+                    }
+                    Some(Some(Ok(collections))) => {
+                        view! {
+                            <ul class="flex flex-col gap-2 mt-2">
+                                {collections
+                                    .into_iter()
+                                    .map(|collection| {
+                                        view! {
+                                            <PendingCollection
+                                                collection=collection
+                                                review=review
+                                                reload=reload
+                                            />
+                                        }
+                                    })
+                                    .collect_view()}
+                            </ul>
+                        }
+                            .into_view()
+                    }
+                    Some(Some(Err(e))) => view! { <p class="text-sm text-red-500">{e}</p> }.into_view(),
+                    _ => ().into_view(),
+                }}
+            </Suspense>
+        </div>
+    }
+}
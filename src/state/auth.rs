@@ -1,129 +1,503 @@
+use crate::consts::auth::{
+    DELEGATION_MAX_AGE, EXPIRY_CHECK_INTERVAL, EXPIRY_WARNING, PROVIDER_STORE, RENEWAL_TIMEOUT,
+    SESSION_LOCK_STORE,
+};
 use crate::state::canisters::Canisters;
+use crate::state::identity_provider::IdentityProvider;
+use crate::utils::now_nanos;
 use candid::Principal;
 use dotenv_codegen::dotenv;
-use futures::executor::block_on;
+use futures::channel::oneshot;
+use futures::future::{select, Either};
+use gloo::storage::{LocalStorage, Storage};
+use gloo::timers::future::TimeoutFuture;
 use ic_agent::{identity::Identity, Agent};
 use ic_auth_client::{AuthClient, AuthClientLoginOptions};
 use leptos::logging;
-use leptos::window;
-use log::{error, info};
-use std::env;
+use leptos::*;
+use log::info;
+use std::cell::RefCell;
 use std::error::Error;
 use std::rc::Rc;
+use std::sync::Arc;
 use std::time::Duration;
-use web_sys::Url;
 pub const TIMEOUT: Duration = Duration::from_secs(60 * 5);
 
 #[derive(Clone)]
 pub struct AuthService {
     auth_client: AuthClient,
-    agent: Option<Rc<Agent>>, // Store agent in Rc for shared ownership
+    /// Provider of the current session, `None` while logged out
+    provider: Option<IdentityProvider>,
+    /// Fixed identity used instead of the auth client's delegation
+    dev_identity: Option<Arc<dyn Identity>>,
 }
 
 impl AuthService {
-    pub fn new() -> Result<Self, String> {
-        let auth_client = block_on(AuthClient::builder().build());
-        Ok(AuthService {
+    /// Load the auth client, restoring the session stored by a previous login
+    pub async fn new() -> Self {
+        let auth_client = AuthClient::builder().build().await;
+        let provider = LocalStorage::get::<IdentityProvider>(PROVIDER_STORE)
+            .ok()
+            .filter(|provider| IdentityProvider::available().contains(provider));
+        let dev_identity = provider.and_then(IdentityProvider::dev_identity);
+        let provider =
+            provider.filter(|_| dev_identity.is_some() || auth_client.is_authenticated());
+        AuthService {
             auth_client,
-            agent: None,
-        })
+            provider,
+            dev_identity,
+        }
     }
 
-    pub async fn login(&mut self) -> Result<(), String> {
-        let mut dfx_network = dotenv!("BACKEND").to_string();
-        if dfx_network.is_empty() {
-            dfx_network = env::var("BACKEND").expect("BACKEND must be set");
+    /// Log in with `provider`, waiting until its window reports back
+    pub async fn login(&mut self, provider: IdentityProvider) -> Result<(), String> {
+        if provider == IdentityProvider::DevIdentity {
+            self.dev_identity = Some(
+                provider
+                    .dev_identity()
+                    .ok_or_else(|| "The dev identity is only available locally".to_string())?,
+            );
+            self.remember(provider);
+            return Ok(());
         }
-        let BACKEND_ID: Principal = Principal::from_text("6zfvq-kiaaa-aaaab-qacra-cai").expect(".");
-        let identity_provider: Option<Url> = match dfx_network.as_str() {
-            "LOCAL" => Some({
-                let port = 4943;
-                let canister_id = BACKEND_ID;
-                Url::new(&format!("http://{}.localhost:{}", canister_id, port)).unwrap()
-            }),
-            "LIVE" => Some(Url::new("https://identity.ic0.app/#authorize").unwrap()),
-            _ => panic!("Unknown dfx network: {}", dfx_network),
-        };
+        let identity_provider = provider
+            .login_url()
+            .ok_or_else(|| format!("No login page for {}", provider.label()))?;
+
+        // Both callbacks share the sender, whichever runs first resolves the login
+        let (sender, receiver) = oneshot::channel::<Result<(), String>>();
+        let sender = Rc::new(RefCell::new(Some(sender)));
+        let on_error_sender = Rc::clone(&sender);
 
-        let mut builder = AuthClientLoginOptions::builder()
-            .max_time_to_live(7 * 24 * 60 * 60 * 1_000_000_000) // 7 days in nanoseconds
-            .on_success(|_| {
-                // Handle successful login
+        let options = AuthClientLoginOptions::builder()
+            .max_time_to_live(DELEGATION_MAX_AGE.as_nanos() as u64)
+            .identity_provider(identity_provider)
+            .on_success(move |_| {
                 info!("Login successful");
-                window().location().reload().unwrap();
+                if let Some(sender) = sender.borrow_mut().take() {
+                    let _ = sender.send(Ok(()));
+                }
             })
-            .on_error(|error| {
-                // Handle login error
+            .on_error(move |error| {
                 logging::log!("Login failed: {:?}", error);
-            });
-
-        // Only set the identity_provider if it's Some
-        if let Some(provider) = identity_provider {
-            builder = builder.identity_provider(provider);
-        }
-
-        let options = builder.build();
+                if let Some(sender) = on_error_sender.borrow_mut().take() {
+                    let _ = sender.send(Err(error.unwrap_or_else(|| "Login failed".to_string())));
+                }
+            })
+            .build();
 
         // Initiate the login process
         self.auth_client.login_with_options(options);
 
+        receiver
+            .await
+            .map_err(|_| "Login window closed".to_string())??;
+
         // Verify authentication after login
         if self.auth_client.is_authenticated() {
+            self.dev_identity = None;
+            self.remember(provider);
             Ok(())
         } else {
             Err("Authentication failed".to_string())
         }
     }
-    pub async fn get_agent(&mut self) -> Result<Rc<Agent>, String> {
-        if self.agent.is_none() {
-            self.agent = Some(Rc::new(create_agent(&self.auth_client).await?));
+
+    fn remember(&mut self, provider: IdentityProvider) {
+        self.provider = Some(provider);
+        if let Err(e) = LocalStorage::set(PROVIDER_STORE, provider) {
+            logging::log!("Failed to store identity provider: {:?}", e);
         }
-        Ok(self.agent.as_ref().unwrap().clone())
+    }
+
+    /// Provider of the current session
+    pub fn provider(&self) -> Option<IdentityProvider> {
+        self.provider
+    }
+
+    /// The identity calls are signed with, anonymous when logged out
+    pub fn identity(&self) -> Arc<dyn Identity> {
+        match &self.dev_identity {
+            Some(identity) => Arc::clone(identity),
+            None => Arc::new(self.auth_client.identity()),
+        }
+    }
+
+    /// When the delegation chain of the current identity stops being valid, `None`
+    /// for identities that sign directly
+    pub fn delegation_expiry(&self) -> Option<u64> {
+        self.identity()
+            .delegation_chain()
+            .iter()
+            .map(|signed| signed.delegation.expiration)
+            .min()
+    }
+
+    /// An agent signing with the current identity
+    pub async fn create_agent(&self) -> Result<Agent, String> {
+        create_agent(self.identity()).await
     }
 
     /// Get the principal (identity's sender)
     pub fn get_principal(&self) -> Result<Principal, Box<dyn Error>> {
-        self.auth_client
-            .identity()
+        self.identity()
             .sender()
             .map_err(|_| "Unable to retrieve principal.".into())
     }
     pub fn is_authenticated(&self) -> bool {
-        self.auth_client.is_authenticated()
+        self.dev_identity.is_some() || self.auth_client.is_authenticated()
     }
 
-    pub async fn logout(&mut self) -> Result<(), String> {
-        // Call the logout method on the AuthClient
-        self.auth_client
-            .logout(Some(web_sys::window().unwrap().location()))
-            .await;
+    pub async fn logout(&mut self) {
+        // Stay on the current page, the app reacts to the cleared identity
+        self.auth_client.logout(None).await;
+        self.dev_identity = None;
+        self.provider = None;
+        LocalStorage::delete(PROVIDER_STORE);
+        info!("Logout successful");
+    }
+}
+
+/// Where the current session stands relative to its delegation's expiry
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SessionStatus {
+    Anonymous,
+    Active,
+    /// Expires within `EXPIRY_WARNING`
+    Expiring,
+    /// Calls signed with the delegation are rejected until the user logs in again
+    Expired,
+}
 
-        // Clear the agent
-        self.agent = None;
+/// Session state provided by `AuthServiceProvider`, updated in place on login
+/// and logout so nothing has to reload the page
+#[derive(Clone, Copy)]
+pub struct AuthState {
+    service: RwSignal<Option<AuthService>>,
+    set_principal: WriteSignal<Option<Principal>>,
+    set_expires_at: WriteSignal<Option<u64>>,
+    canisters: RwSignal<Option<Rc<Canisters>>>,
+    /// Principal and delegation expiry the current `canisters` were built for
+    canisters_identity: RwSignal<Option<(Option<Principal>, Option<u64>)>>,
+    /// Principal and delegation expiry building `canisters` last failed for, and why
+    canisters_failure: RwSignal<Option<((Option<Principal>, Option<u64>), String)>>,
+    /// Logins that failed or were cancelled, so paused uploads can stop waiting
+    failed_logins: RwSignal<usize>,
+    locked: RwSignal<bool>,
+    /// Uploads currently running, which keep an idle session alive
+    uploads: RwSignal<usize>,
+    /// The logged in principal, `None` while anonymous
+    pub principal: ReadSignal<Option<Principal>>,
+    pub is_authenticated: Signal<bool>,
+    /// Whether the stored session has been checked yet
+    pub restored: Signal<bool>,
+    /// When the delegation expires, in nanoseconds since the epoch
+    pub expires_at: ReadSignal<Option<u64>>,
+    pub status: Signal<SessionStatus>,
+}
 
-        // Reload the page
-        web_sys::window()
-            .unwrap()
-            .location()
-            .reload()
-            .map_err(|_| "Failed to reload page".to_string())?;
+impl AuthState {
+    /// Session state that keeps `canisters` signing with the current identity
+    pub fn new(canisters: RwSignal<Option<Rc<Canisters>>>) -> Self {
+        let service = create_rw_signal(None::<AuthService>);
+        let (principal, set_principal) = create_signal(None::<Principal>);
+        let (expires_at, set_expires_at) = create_signal(None::<u64>);
 
-        // Log the logout action
-        info!("Logout successful");
+        let (now, set_now) = create_signal(now_nanos());
+        if let Ok(clock) =
+            set_interval_with_handle(move || set_now.set(now_nanos()), EXPIRY_CHECK_INTERVAL)
+        {
+            on_cleanup(move || clock.clear());
+        }
+        let status = Signal::derive(move || match (principal.get(), expires_at.get()) {
+            (None, _) => SessionStatus::Anonymous,
+            (Some(_), Some(expires_at)) if expires_at <= now.get() => SessionStatus::Expired,
+            (Some(_), Some(expires_at))
+                if expires_at <= now.get() + EXPIRY_WARNING.as_nanos() as u64 =>
+            {
+                SessionStatus::Expiring
+            }
+            (Some(_), _) => SessionStatus::Active,
+        });
+
+        let state = Self {
+            service,
+            set_principal,
+            set_expires_at,
+            canisters,
+            canisters_identity: create_rw_signal(None),
+            canisters_failure: create_rw_signal(None),
+            failed_logins: create_rw_signal(0),
+            locked: create_rw_signal(false),
+            uploads: create_rw_signal(0),
+            principal,
+            is_authenticated: Signal::derive(move || principal.with(Option::is_some)),
+            restored: Signal::derive(move || service.with(Option::is_some)),
+            expires_at,
+            status,
+        };
+        state.rebuild_canisters();
+        state
+    }
+
+    /// Load the auth client and pick up the session stored by an earlier visit
+    pub async fn restore(self) {
+        let service = AuthService::new().await;
+        self.service.set(Some(service));
+        self.sync();
+        // A session locked before a reload stays locked
+        let locked_principal = LocalStorage::get::<String>(SESSION_LOCK_STORE).ok();
+        self.locked.set(
+            locked_principal.is_some()
+                && locked_principal == self.principal.get_untracked().map(|p| p.to_text()),
+        );
+    }
+
+    /// Copy the client's current identity into the signals
+    fn sync(self) {
+        let (principal, expires_at) = self.service.with_untracked(|service| {
+            match service
+                .as_ref()
+                .filter(|service| service.is_authenticated())
+            {
+                Some(service) => (service.get_principal().ok(), service.delegation_expiry()),
+                None => (None, None),
+            }
+        });
+        self.set_principal.set(principal);
+        self.set_expires_at.set(expires_at);
+    }
+
+    /// The loaded auth client, tracked so callers rerun once it is restored
+    pub fn service(self) -> Option<AuthService> {
+        self.service.get()
+    }
+
+    /// Log in, or renew an expiring delegation, in the identity provider's window.
+    /// The page stays as it is, so form state survives.
+    pub async fn login(self, provider: IdentityProvider) -> Result<(), String> {
+        let mut service = self
+            .service
+            .get_untracked()
+            .ok_or_else(|| "Session is still loading".to_string())?;
+        let result = service.login(provider).await;
+        self.service.set(Some(service));
+        self.sync();
+        if result.is_err() {
+            self.failed_logins.update(|count| *count += 1);
+        }
+        result
+    }
+
+    /// Provider to log in again with, the one of the current or last session if
+    /// any. Tracked, so it follows the stored session once that is restored.
+    pub fn provider(self) -> IdentityProvider {
+        self.service
+            .with(|service| service.as_ref().and_then(AuthService::provider))
+            .unwrap_or_else(IdentityProvider::default_for_network)
+    }
+
+    pub async fn logout(self) {
+        if let Some(mut service) = self.service.get_untracked() {
+            service.logout().await;
+            self.service.set(Some(service));
+        }
+        self.sync();
+        self.set_locked(false);
+    }
 
+    /// Whether the app is hidden behind the lock screen
+    pub fn is_locked(self) -> bool {
+        self.locked.get()
+    }
+
+    /// Hide the app until the same identity logs in again
+    pub fn lock(self) {
+        if self.principal.get_untracked().is_some() {
+            self.set_locked(true);
+        }
+    }
+
+    /// Log in again to lift the lock. Another identity can't take over the locked
+    /// session, so logging in as someone else logs out instead.
+    pub async fn unlock(self) -> Result<(), String> {
+        let locked_principal = self.principal.get_untracked();
+        self.login(self.provider()).await?;
+        if self.principal.get_untracked() != locked_principal {
+            self.logout().await;
+            return Err(
+                "Logged in as a different identity, the locked session was logged out".to_string(),
+            );
+        }
+        self.set_locked(false);
         Ok(())
     }
+
+    fn set_locked(self, locked: bool) {
+        match self.principal.get_untracked().filter(|_| locked) {
+            Some(principal) => {
+                if let Err(e) = LocalStorage::set(SESSION_LOCK_STORE, principal.to_text()) {
+                    logging::log!("Failed to store session lock: {:?}", e);
+                }
+            }
+            None => LocalStorage::delete(SESSION_LOCK_STORE),
+        }
+        self.locked.set(locked);
+    }
+
+    /// Whether an upload is running, tracked
+    pub fn is_uploading(self) -> bool {
+        self.uploads.with(|count| *count > 0)
+    }
+
+    /// Count an upload as running until the returned guard is dropped
+    pub fn track_upload(self) -> UploadGuard {
+        self.uploads.update(|count| *count += 1);
+        UploadGuard(self.uploads)
+    }
+
+    /// Treat the session as expired after a call was refused for it, ahead of the
+    /// next clock tick
+    pub fn mark_expired(self) {
+        if self.principal.get_untracked().is_some() {
+            self.set_expires_at.set(Some(0));
+        }
+    }
+
+    /// Canisters signing with a live delegation. While the session is expired
+    /// this waits for the user to log in again, which is how uploads pause. It
+    /// fails once logged out, when logging in again fails or is cancelled, when
+    /// the renewed session's canisters can't be built, or after `RENEWAL_TIMEOUT`.
+    pub async fn active_canisters(self) -> Result<Rc<Canisters>, String> {
+        let failed_logins = self.failed_logins.get_untracked();
+        let mut timeout = TimeoutFuture::new(RENEWAL_TIMEOUT.as_millis() as u32);
+        loop {
+            if self.principal.get_untracked().is_none() {
+                return Err("Logged out".to_string());
+            }
+            if self.failed_logins.get_untracked() != failed_logins {
+                return Err("Logging in again failed or was cancelled".to_string());
+            }
+            let identity = (
+                self.principal.get_untracked(),
+                self.expires_at.get_untracked(),
+            );
+            if let Some((_, e)) = self
+                .canisters_failure
+                .get_untracked()
+                .filter(|(failed, _)| *failed == identity)
+            {
+                return Err(format!("Failed to create Canisters: {}", e));
+            }
+            let renewed = self.canisters_identity.get_untracked() == Some(identity);
+            if renewed && self.status.get_untracked() != SessionStatus::Expired {
+                return self
+                    .canisters
+                    .get_untracked()
+                    .ok_or_else(|| "Canisters are not available".to_string());
+            }
+            match select(Box::pin(self.session_changed()), timeout).await {
+                Either::Left((_, pending)) => timeout = pending,
+                Either::Right(_) => {
+                    return Err("Timed out waiting for the session to be renewed".to_string())
+                }
+            }
+        }
+    }
+
+    /// Resolve on the next change of the session or of the canisters built for it
+    async fn session_changed(self) {
+        let (sender, receiver) = oneshot::channel();
+        let sender = Rc::new(RefCell::new(Some(sender)));
+        let stop = watch(
+            move || {
+                (
+                    self.principal.get(),
+                    self.status.get(),
+                    self.canisters_identity.get(),
+                    self.canisters_failure.get(),
+                    self.failed_logins.get(),
+                )
+            },
+            move |_, _, _| {
+                if let Some(sender) = sender.borrow_mut().take() {
+                    let _ = sender.send(());
+                }
+            },
+            false,
+        );
+        let _ = receiver.await;
+        stop();
+    }
+
+    /// Canisters of a logged in session, asking the user to log in first when
+    /// browsing anonymously. Used before anything that creates, edits or uploads.
+    pub async fn require_login(self) -> Result<Rc<Canisters>, String> {
+        if !self.is_authenticated.get_untracked() {
+            self.login(self.provider()).await?;
+        }
+        self.active_canisters().await
+    }
+
+    /// Build `Canisters` whenever the identity or its delegation changes. Until the
+    /// stored session is restored, and after logout, they are anonymous and can
+    /// only read public data. An expired delegation, including one marked expired
+    /// by `mark_expired`, is not rebuilt since its calls would be refused anyway.
+    fn rebuild_canisters(self) {
+        create_effect(move |_| {
+            let principal = self.principal.get();
+            let expires_at = self.expires_at.get();
+            let service = self.service();
+            if self.status.get_untracked() == SessionStatus::Expired {
+                return;
+            }
+            spawn_local(async move {
+                let canisters = match service {
+                    Some(service) => Canisters::new(service).await,
+                    None => Canisters::anonymous().await,
+                };
+                match canisters {
+                    // A newer login may have finished while this one was building
+                    Ok(canisters)
+                        if self.principal.get_untracked() == principal
+                            && self.expires_at.get_untracked() == expires_at =>
+                    {
+                        self.canisters.set(Some(Rc::new(canisters)));
+                        self.canisters_identity.set(Some((principal, expires_at)));
+                        self.canisters_failure.set(None);
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        logging::log!("Failed to create Canisters: {:?}", e);
+                        self.canisters_failure
+                            .set(Some(((principal, expires_at), e)));
+                    }
+                }
+            });
+        });
+    }
 }
 
-async fn create_agent(auth_client: &AuthClient) -> Result<Agent, String> {
-    let identity = auth_client.identity();
+/// Marks an upload as running while it is alive
+pub struct UploadGuard(RwSignal<usize>);
 
-    let mut dfx_network = dotenv!("BACKEND").to_string();
-    if dfx_network.is_empty() {
-        dfx_network = env::var("DFX_NETWORK").expect("DFX_NETWORK must be set");
+impl Drop for UploadGuard {
+    fn drop(&mut self) {
+        self.0.update(|count| *count = count.saturating_sub(1));
     }
+}
+
+/// The session state, provided by `AuthServiceProvider`
+pub fn use_auth() -> AuthState {
+    use_context::<AuthState>().expect("AuthState should be provided by AuthServiceProvider")
+}
+
+/// An agent for the current network signing with `identity`
+pub async fn create_agent(identity: Arc<dyn Identity>) -> Result<Agent, String> {
+    // The same build-time setting that picks canister ids and identity providers
+    let dfx_network = dotenv!("BACKEND");
 
-    let url = match dfx_network.as_str() {
+    let url = match dfx_network {
         "LOCAL" => "http://127.0.0.1:4943".to_string(),
         "LIVE" => "https://ic0.app".to_string(),
         _ => return Err(format!("Unknown DFX network: {}", dfx_network)),
@@ -131,7 +505,7 @@ async fn create_agent(auth_client: &AuthClient) -> Result<Agent, String> {
 
     let agent = Agent::builder()
         .with_url(url)
-        .with_identity(identity)
+        .with_arc_identity(identity)
         .with_ingress_expiry(Some(TIMEOUT))
         .build()
         .map_err(|e| format!("Failed to build agent: {}", e))?;
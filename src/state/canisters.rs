@@ -4,39 +4,52 @@ use crate::canister::asset_proxy::StoreArg;
 use crate::canister::generated::asset_proxy::AssetProxy;
 use crate::canister::provision::Provision;
 
-// use crate::canister::provision::PROVISION_ID;
+use crate::canister::PROVISION_ID;
+use crate::consts::ASSET_CANISTER_ID;
 use dotenv_codegen::dotenv;
 use ic_agent::AgentError;
 // use crate::state::asset_manager::AssetManager;
-use crate::state::auth::AuthService;
+use crate::state::auth::{create_agent, AuthService};
 use candid::Encode;
 use candid::Principal;
+use ic_agent::identity::AnonymousIdentity;
 use ic_agent::Agent;
 use leptos::*;
-use std::cell::RefCell;
 use std::cmp::PartialEq;
 use std::rc::Rc;
+use std::sync::Arc;
+/// The shared asset canister, used for collections without a canister of their own
+pub fn default_asset_canister() -> Principal {
+    Principal::from_text(ASSET_CANISTER_ID).expect("Invalid principal")
+}
+
 #[derive(Clone)]
 pub struct Canisters {
-    pub auth_service: Rc<RefCell<AuthService>>,
+    /// `None` for the anonymous canisters used before login
+    pub auth_service: Option<AuthService>,
     pub agent: Rc<Agent>,
     provision_principal: Principal,
 }
 
 impl Canisters {
-    pub async fn new(auth_service: Rc<RefCell<AuthService>>) -> Result<Self, String> {
-        let agent = {
-            let mut auth_service_borrow = auth_service.borrow_mut();
-            auth_service_borrow.get_agent().await?
-        };
-        let PROVISION_ID: Principal =
-            Principal::from_text("6zfvq-kiaaa-aaaab-qacra-cai").expect(".");
+    pub async fn new(auth_service: AuthService) -> Result<Self, String> {
+        let agent = auth_service.create_agent().await?;
+        Ok(Self::with_agent(Some(auth_service), agent))
+    }
 
-        Ok(Self {
+    /// Canisters for read-only queries by a visitor who hasn't logged in
+    pub async fn anonymous() -> Result<Self, String> {
+        let agent = create_agent(Arc::new(AnonymousIdentity)).await?;
+        Ok(Self::with_agent(None, agent))
+    }
+
+    /// Provision canister of the network picked by `BACKEND`, see `did/canister_ids.json`
+    fn with_agent(auth_service: Option<AuthService>, agent: Agent) -> Self {
+        Self {
             auth_service,
-            agent,
+            agent: Rc::new(agent),
             provision_principal: PROVISION_ID,
-        })
+        }
     }
 
     pub async fn provision_canister(&self) -> Provision<'_> {
@@ -0,0 +1,118 @@
+use candid::Principal;
+use serde::{Deserialize, Serialize};
+
+/// Define the CarCollection struct
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct CarCollection {
+    pub id: u64,
+    pub name: String,
+    pub model: String,
+    pub logo: String, // Asset reference (e.g., URL or asset ID)
+    pub images: Vec<CollectionImage>,
+    pub documents: Vec<CollectionDocument>,
+    pub owner: Principal,
+    /// Asset canister provisioned for this collection's files
+    pub asset_canister: Option<Principal>,
+}
+
+/// An image of the collection with the text shown alongside it
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CollectionImage {
+    pub key: String,
+    pub caption: String,
+    /// Description read by screen readers, the caption is used when empty
+    pub alt: String,
+}
+
+impl CollectionImage {
+    pub fn new(key: String) -> Self {
+        Self {
+            key,
+            ..Default::default()
+        }
+    }
+
+    /// Text for the `alt` attribute, never empty
+    pub fn alt_text(&self, position: usize) -> String {
+        if !self.alt.trim().is_empty() {
+            self.alt.clone()
+        } else if !self.caption.trim().is_empty() {
+            self.caption.clone()
+        } else {
+            format!("Collection image {}", position + 1)
+        }
+    }
+}
+
+/// What a collection document is, as chosen by its uploader
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DocumentCategory {
+    Registration,
+    Insurance,
+    InspectionReport,
+    Invoice,
+    #[default]
+    Other,
+}
+
+impl DocumentCategory {
+    /// Every category, in the order documents are grouped
+    pub const ALL: [Self; 5] = [
+        Self::Registration,
+        Self::Insurance,
+        Self::InspectionReport,
+        Self::Invoice,
+        Self::Other,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Registration => "Registration",
+            Self::Insurance => "Insurance",
+            Self::InspectionReport => "Inspection report",
+            Self::Invoice => "Invoice",
+            Self::Other => "Other",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|category| category.label() == label)
+    }
+}
+
+/// An uploaded document with what it is, who issued it and until when it is valid
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CollectionDocument {
+    pub key: String,
+    pub category: DocumentCategory,
+    pub issuer: String,
+    /// Nanoseconds since the epoch, like canister timestamps
+    pub expires_at: Option<u64>,
+}
+
+impl CollectionDocument {
+    pub fn new(key: String) -> Self {
+        Self {
+            key,
+            ..Default::default()
+        }
+    }
+}
+
+// Implement Default manually due to Principal not implementing Default
+impl Default for CarCollection {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            name: String::new(),
+            model: String::new(),
+            logo: String::new(),
+            images: Vec::new(),
+            documents: Vec::new(),
+            owner: Principal::anonymous(),
+            asset_canister: None,
+        }
+    }
+}
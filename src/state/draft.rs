@@ -0,0 +1,41 @@
+use crate::consts::draft::DRAFT_STORE_PREFIX;
+use crate::state::collection::CarCollection;
+use candid::Principal;
+use gloo::storage::{LocalStorage, Storage};
+use leptos::logging::log;
+
+fn draft_key(principal: Principal) -> String {
+    format!("{}-{}", DRAFT_STORE_PREFIX, principal)
+}
+
+/// The collection form as last autosaved by `principal`
+pub fn load_draft(principal: Principal) -> Option<CarCollection> {
+    LocalStorage::get(draft_key(principal)).ok()
+}
+
+pub fn save_draft(principal: Principal, collection: &CarCollection) {
+    if let Err(e) = LocalStorage::set(draft_key(principal), collection) {
+        log!("Failed to save draft: {:?}", e);
+    }
+}
+
+pub fn clear_draft(principal: Principal) {
+    LocalStorage::delete(draft_key(principal));
+}
+
+/// Asset keys uploaded for a draft, which stay in the asset canister if it is discarded.
+///
+/// Only documents are offered, under their `/file/<sha>/<name>` key which other
+/// collections share only if they hold the very same file. Images and logos are
+/// stored under their content hash alone (`/img/<sha>`) and are often reused, and
+/// older `/file-<name>` keys are shared by every document with that name, so both
+/// are left out and never deleted.
+pub fn uploaded_keys(collection: &CarCollection) -> Vec<String> {
+    collection
+        .documents
+        .iter()
+        .map(|document| &document.key)
+        .filter(|key| key.starts_with("/file/"))
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,80 @@
+use crate::canister::INTERNET_IDENTITY_ID;
+use crate::consts::auth::{DEV_IDENTITY_PEM, II_URL, NFID_URL};
+use crate::consts::local::II_HOST;
+use dotenv_codegen::dotenv;
+use ic_agent::identity::{BasicIdentity, Identity, Secp256k1Identity};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use web_sys::Url;
+
+/// Where a user logs in from
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IdentityProvider {
+    InternetIdentity,
+    /// Internet Identity deployed on the local replica, see `did/canister_ids.json`
+    LocalInternetIdentity,
+    Nfid,
+    /// `DEV_IDENTITY_PEM`, signing directly without a login window. Only offered when
+    /// the key was provided to a local replica build.
+    DevIdentity,
+}
+
+impl IdentityProvider {
+    /// Providers whose delegations the current network accepts
+    pub fn available() -> Vec<Self> {
+        if dotenv!("BACKEND") == "LOCAL" {
+            let mut providers = vec![Self::LocalInternetIdentity];
+            if DEV_IDENTITY_PEM.is_some() {
+                providers.push(Self::DevIdentity);
+            }
+            providers
+        } else {
+            vec![Self::InternetIdentity, Self::Nfid]
+        }
+    }
+
+    /// Offered first in the picker
+    pub fn default_for_network() -> Self {
+        Self::available()[0]
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::InternetIdentity => "Internet Identity",
+            Self::LocalInternetIdentity => "Internet Identity (local)",
+            Self::Nfid => "NFID",
+            Self::DevIdentity => "Dev identity",
+        }
+    }
+
+    /// Login page opened by the auth client, `None` for the dev identity
+    pub fn login_url(self) -> Option<Url> {
+        let url = match self {
+            Self::InternetIdentity => II_URL.to_string(),
+            Self::LocalInternetIdentity => {
+                format!("http://{}.{}/#authorize", INTERNET_IDENTITY_ID, II_HOST)
+            }
+            Self::Nfid => NFID_URL.to_string(),
+            Self::DevIdentity => return None,
+        };
+        Url::new(&url).ok()
+    }
+
+    /// The fixed identity of `DevIdentity`, only on a local replica
+    pub fn dev_identity(self) -> Option<Arc<dyn Identity>> {
+        if self != Self::DevIdentity || !Self::available().contains(&self) {
+            return None;
+        }
+        let pem = DEV_IDENTITY_PEM?;
+        if let Ok(identity) = BasicIdentity::from_pem(pem.as_bytes()) {
+            return Some(Arc::new(identity));
+        }
+        match Secp256k1Identity::from_pem(pem.as_bytes()) {
+            Ok(identity) => Some(Arc::new(identity)),
+            Err(e) => {
+                log::error!("DEV_IDENTITY_PEM is neither Ed25519 nor secp256k1: {}", e);
+                None
+            }
+        }
+    }
+}
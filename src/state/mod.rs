@@ -1,3 +1,6 @@
 pub mod auth;
 pub mod auth_actions;
 pub mod canisters;
+pub mod collection;
+pub mod draft;
+pub mod identity_provider;
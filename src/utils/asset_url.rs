@@ -0,0 +1,60 @@
+use crate::consts::{local, remote};
+use crate::state::canisters::default_asset_canister;
+use candid::Principal;
+use dotenv_codegen::dotenv;
+
+/// How a stored asset key is turned into a URL the browser can load
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssetUrl {
+    /// `<id>.icp0.io`, responses are verified against the asset certification
+    Certified,
+    /// `<id>.localhost:4943`, a canister on the local replica
+    Local,
+}
+
+impl AssetUrl {
+    /// Certified URLs on mainnet, local ones when the app talks to a local replica
+    pub fn for_network() -> Self {
+        match dotenv!("BACKEND") {
+            "LOCAL" => AssetUrl::Local,
+            _ => AssetUrl::Certified,
+        }
+    }
+
+    /// Origin of `canister_id`, without a trailing slash
+    pub fn origin(self, canister_id: Principal) -> String {
+        match self {
+            AssetUrl::Certified => format!("https://{}.{}", canister_id, remote::ASSET_HOST),
+            AssetUrl::Local => format!("http://{}.{}", canister_id, local::ASSET_HOST),
+        }
+    }
+
+    /// URL of `key` in `canister_id`. Keys that already are URLs are returned as they are.
+    pub fn resolve(self, canister_id: Principal, key: &str) -> String {
+        if key.starts_with("http://") || key.starts_with("https://") || key.starts_with("blob:") {
+            return key.to_string();
+        }
+        let path = String::from(js_sys::encode_uri(key));
+        if path.starts_with('/') {
+            format!("{}{}", self.origin(canister_id), path)
+        } else {
+            format!("{}/{}", self.origin(canister_id), path)
+        }
+    }
+}
+
+/// URL of `key` for the current network, in the shared canister when `canister_id` is unset
+pub fn asset_url(canister_id: Option<Principal>, key: &str) -> String {
+    AssetUrl::for_network().resolve(canister_id.unwrap_or_else(default_asset_canister), key)
+}
+
+/// In-app viewer of the document stored under `key`
+pub fn document_href(canister_id: Option<Principal>, key: &str) -> String {
+    format!(
+        "/documents{}{}",
+        key,
+        canister_id
+            .map(|c| format!("?canister={}", c))
+            .unwrap_or_default()
+    )
+}
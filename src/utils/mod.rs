@@ -0,0 +1,72 @@
+pub mod asset_url;
+pub mod pdf;
+pub mod validation;
+
+use crate::state::canisters::default_asset_canister;
+use candid::Principal;
+use leptos::logging::log;
+use leptos::window;
+use leptos::SignalWithUntracked;
+use leptos_router::use_query_map;
+
+/// Lowercase hex encoding, used to display hashes and evidence
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Decode hex produced by `to_hex`, accepting either case
+pub fn from_hex(hex: &str) -> Result<Vec<u8>, String> {
+    let hex = hex.trim();
+    if hex.len() % 2 != 0 {
+        return Err("Hex string has an odd length".to_string());
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(|| format!("Invalid hex at position {}", i))
+        })
+        .collect()
+}
+
+/// Copy `text` to the clipboard, used for principals, commands and evidence
+pub fn copy_to_clipboard(text: String) {
+    // The returned promise only reports permission errors, which we can't act on
+    let _ = window().navigator().clipboard().write_text(&text);
+    log!("Copied to clipboard: {}", text);
+}
+
+/// Asset canister named by the `?canister=` query parameter, or the shared one when
+/// it is absent. A parameter that is not a principal is an error rather than a silent
+/// fallback, so admin actions never run against the wrong canister.
+pub fn use_asset_canister_query() -> Result<Principal, String> {
+    use_query_map().with_untracked(|q| match q.get("canister") {
+        Some(text) => {
+            Principal::from_text(text).map_err(|e| format!("Invalid canister id {}: {}", text, e))
+        }
+        None => Ok(default_asset_canister()),
+    })
+}
+
+/// ISO 8601 form of a canister timestamp in nanoseconds since the epoch
+pub fn format_timestamp(nanos: u64) -> String {
+    let millis = (nanos / 1_000_000) as f64;
+    String::from(js_sys::Date::new(&millis.into()).to_iso_string())
+}
+
+/// The current time in nanoseconds since the epoch, comparable to canister timestamps
+pub fn now_nanos() -> u64 {
+    js_sys::Date::now() as u64 * 1_000_000
+}
+
+/// `YYYY-MM-DD` value of an `<input type="date">` for a timestamp in nanoseconds
+pub fn date_input_value(nanos: u64) -> String {
+    format_timestamp(nanos).chars().take(10).collect()
+}
+
+/// Timestamp in nanoseconds of the UTC midnight starting a `YYYY-MM-DD` date
+pub fn parse_date_input(date: &str) -> Option<u64> {
+    let millis = js_sys::Date::parse(date);
+    (!millis.is_nan() && millis >= 0.0).then(|| millis as u64 * 1_000_000)
+}
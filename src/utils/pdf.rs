@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+/// Number of pages in a PDF, read from its uncompressed page objects.
+///
+/// An incrementally updated file appends new revisions of changed objects, so
+/// objects are counted by number and only their last definition is used.
+/// Returns `None` for files with compressed object streams (`/ObjStm`), since
+/// any of their pages could be inside one and reading those would need a full
+/// PDF parser.
+pub fn pdf_page_count(content: &[u8]) -> Option<usize> {
+    let mut is_page_by_number = HashMap::new();
+    for (number, body) in objects(content) {
+        if has_type(body, b"/ObjStm") {
+            return None;
+        }
+        is_page_by_number.insert(number, has_type(body, b"/Page"));
+    }
+    let pages = is_page_by_number
+        .values()
+        .filter(|is_page| **is_page)
+        .count();
+    (pages > 0).then_some(pages)
+}
+
+/// Whether `content` starts like a PDF file
+pub fn is_pdf(content: &[u8]) -> bool {
+    content.starts_with(b"%PDF-")
+}
+
+/// Every `<number> <generation> obj ... endobj` in file order, with its body
+fn objects(content: &[u8]) -> Vec<(u64, &[u8])> {
+    const OBJ: &[u8] = b"obj";
+    const END_OBJ: &[u8] = b"endobj";
+    let mut objects = Vec::new();
+    for (i, window) in content.windows(OBJ.len()).enumerate() {
+        if window != OBJ
+            || content
+                .get(i + OBJ.len())
+                .is_some_and(|c| c.is_ascii_alphanumeric())
+        {
+            continue;
+        }
+        let Some(number) = object_number(&content[..i]) else {
+            continue;
+        };
+        let body = &content[i + OBJ.len()..];
+        let end = find(body, END_OBJ).unwrap_or(body.len());
+        objects.push((number, &body[..end]));
+    }
+    objects
+}
+
+/// Object number of a header whose `obj` keyword follows `head`
+fn object_number(head: &[u8]) -> Option<u64> {
+    // `endobj` and other words ending in `obj` are not preceded by a space
+    if !head.last().is_some_and(|c| c.is_ascii_whitespace()) {
+        return None;
+    }
+    let (_generation, rest) = trailing_number(trim_end(head))?;
+    if !rest.last().is_some_and(|c| c.is_ascii_whitespace()) {
+        return None;
+    }
+    let (number, rest) = trailing_number(trim_end(rest))?;
+    (!rest.last().is_some_and(|c| c.is_ascii_alphanumeric())).then_some(number)
+}
+
+/// Whether an object body has the name `kind`, such as `/Page`, as its `/Type`
+fn has_type(body: &[u8], kind: &[u8]) -> bool {
+    const TYPE: &[u8] = b"/Type";
+    body.windows(TYPE.len())
+        .enumerate()
+        .filter(|(_, window)| *window == TYPE)
+        .any(|(i, _)| {
+            let value = trim_start(&body[i + TYPE.len()..]);
+            // Skip `/Pages`, `/PageLabels` and other names starting with `kind`
+            value.starts_with(kind)
+                && !value
+                    .get(kind.len())
+                    .is_some_and(|c| c.is_ascii_alphanumeric())
+        })
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn trailing_number(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    let digits = bytes
+        .iter()
+        .rev()
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+    let (rest, digits) = bytes.split_at(bytes.len() - digits);
+    let number = std::str::from_utf8(digits).ok()?.parse().ok()?;
+    Some((number, rest))
+}
+
+fn trim_start(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|c| !c.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+fn trim_end(bytes: &[u8]) -> &[u8] {
+    let end = bytes
+        .iter()
+        .rposition(|c| !c.is_ascii_whitespace())
+        .map_or(0, |i| i + 1);
+    &bytes[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINIMAL: &[u8] = b"%PDF-1.4
+1 0 obj
+<< /Type /Catalog /Pages 2 0 R >>
+endobj
+2 0 obj
+<< /Type /Pages /Kids [3 0 R 4 0 R] /Count 2 >>
+endobj
+3 0 obj
+<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>
+endobj
+4 0 obj
+<</Type/Page/Parent 2 0 R/MediaBox [0 0 612 792]>>
+endobj
+trailer
+<< /Root 1 0 R >>
+%%EOF
+";
+
+    /// `MINIMAL` with a revision that rewrites page 4 and appends page 5
+    const UPDATE: &[u8] = b"4 0 obj
+<< /Type /Page /Parent 2 0 R /Rotate 90 >>
+endobj
+2 0 obj
+<< /Type /Pages /Kids [3 0 R 4 0 R 5 0 R] /Count 3 >>
+endobj
+5 0 obj
+<< /Type /Page /Parent 2 0 R >>
+endobj
+trailer
+<< /Root 1 0 R /Prev 9 >>
+%%EOF
+";
+
+    /// A PDF 1.5 file whose catalog, page tree and page are compressed into one
+    /// object stream, as most current PDF writers produce. The cross-reference
+    /// stream is a placeholder, since the page count never reads it.
+    const OBJECT_STREAM: &[u8] = b"%PDF-1.5\n\
+4 0 obj\n\
+<< /Type /ObjStm /N 3 /First 14 /Filter /FlateDecode /Length 110 >>\n\
+stream\nx\x9c3T0P0R06Q0V07\xe3\xb2\xb1Q\xd0\x0f\xa9,HU\xd0wN,I\xcc\xc9OW\xd0\x0fHLO-\x06*1P\x08R\xb0\xb3CR\x01\x91\xd0\xf7\xceL)V\x886\x06\xc9\xc7\x02\xb5\xe5\x97\xe6\x95(\x18b\xaa\x04\x91E\xa9@9\x88I\xfa\xbe\xa9)\x99\x89N\xf9\x15\x0a\xd1\x06@\x013C#\x05sK\xa3X\x90>\x00\xdah&\x22\n\
+endstream\n\
+endobj\n\
+5 0 obj\n\
+<< /Type /XRef /Size 6 /W [1 2 1] /Root 1 0 R /Filter /FlateDecode >>\n\
+stream\n\
+...\n\
+endstream\n\
+endobj\n\
+startxref\n\
+0\n\
+%%EOF\n";
+
+    #[test]
+    fn counts_pages_of_minimal_pdf() {
+        assert!(is_pdf(MINIMAL));
+        assert_eq!(pdf_page_count(MINIMAL), Some(2));
+    }
+
+    #[test]
+    fn counts_rewritten_objects_of_incremental_updates_once() {
+        let updated = [MINIMAL, UPDATE].concat();
+        assert_eq!(pdf_page_count(&updated), Some(3));
+    }
+
+    #[test]
+    fn counts_page_ending_the_content() {
+        assert_eq!(pdf_page_count(b"1 0 obj << /Type /Page"), Some(1));
+    }
+
+    #[test]
+    fn no_page_count_with_object_streams() {
+        assert!(is_pdf(OBJECT_STREAM));
+        assert_eq!(pdf_page_count(OBJECT_STREAM), None);
+        // Uncompressed pages don't tell how many more are in the object stream
+        let mixed = [MINIMAL, OBJECT_STREAM].concat();
+        assert_eq!(pdf_page_count(&mixed), None);
+    }
+
+    #[test]
+    fn no_pages_without_page_objects() {
+        assert_eq!(
+            pdf_page_count(b"%PDF-1.4\n1 0 obj << /Type /Catalog >> endobj"),
+            None
+        );
+        assert_eq!(pdf_page_count(b""), None);
+    }
+}
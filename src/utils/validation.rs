@@ -0,0 +1,321 @@
+use crate::state::collection::{CarCollection, CollectionDocument, DocumentCategory};
+
+/// A `CarCollection` field checked before submission
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Field {
+    Name,
+    Model,
+    Logo,
+    Images,
+    Documents,
+}
+
+/// A single constraint on a field. Text rules apply to the trimmed value, count
+/// rules to the number of uploaded keys.
+#[derive(Clone, Copy, Debug)]
+pub enum Rule {
+    Required,
+    MinLength(usize),
+    MaxLength(usize),
+    /// Letters, digits, spaces and the listed punctuation only
+    AllowedChars(&'static str),
+    MinCount(usize),
+    MaxCount(usize),
+}
+
+/// Every rule of the collection form, in the order errors are reported
+pub const COLLECTION_RULES: &[(Field, &[Rule])] = &[
+    (
+        Field::Name,
+        &[Rule::Required, Rule::MinLength(3), Rule::MaxLength(80)],
+    ),
+    (
+        Field::Model,
+        &[
+            Rule::Required,
+            Rule::MaxLength(64),
+            Rule::AllowedChars("-./()"),
+        ],
+    ),
+    (Field::Logo, &[Rule::Required]),
+    (Field::Images, &[Rule::MinCount(3), Rule::MaxCount(20)]),
+    (Field::Documents, &[Rule::MinCount(1), Rule::MaxCount(10)]),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldError {
+    pub field: Field,
+    pub message: String,
+}
+
+enum Value<'a> {
+    Text(&'a str),
+    Count(usize),
+}
+
+impl Field {
+    pub fn label(self) -> &'static str {
+        match self {
+            Field::Name => "Name",
+            Field::Model => "Model",
+            Field::Logo => "Logo",
+            Field::Images => "Images",
+            Field::Documents => "Documents",
+        }
+    }
+
+    fn value(self, collection: &CarCollection) -> Value<'_> {
+        match self {
+            Field::Name => Value::Text(collection.name.trim()),
+            Field::Model => Value::Text(collection.model.trim()),
+            Field::Logo => Value::Text(&collection.logo),
+            Field::Images => Value::Count(collection.images.len()),
+            Field::Documents => Value::Count(collection.documents.len()),
+        }
+    }
+}
+
+impl Rule {
+    /// Why `value` breaks this rule, if it does
+    fn check(self, label: &str, value: &Value) -> Option<String> {
+        match (self, value) {
+            (Rule::Required, Value::Text(text)) if text.is_empty() => {
+                Some(format!("{} is required", label))
+            }
+            (Rule::MinLength(min), Value::Text(text))
+                if !text.is_empty() && text.chars().count() < min =>
+            {
+                Some(format!("{} must be at least {} characters", label, min))
+            }
+            (Rule::MaxLength(max), Value::Text(text)) if text.chars().count() > max => {
+                Some(format!("{} must be at most {} characters", label, max))
+            }
+            (Rule::AllowedChars(extra), Value::Text(text))
+                if !text
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == ' ' || extra.contains(c)) =>
+            {
+                Some(format!(
+                    "{} may only contain letters, digits, spaces and {}",
+                    label, extra
+                ))
+            }
+            (Rule::MinCount(min), Value::Count(count)) if *count < min => Some(format!(
+                "{} needs at least {} file(s), {} uploaded",
+                label, min, count
+            )),
+            (Rule::MaxCount(max), Value::Count(count)) if *count > max => Some(format!(
+                "{} allows at most {} file(s), {} uploaded",
+                label, max, count
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// The first broken rule of every field, empty when the collection can be submitted
+pub fn validate_collection(collection: &CarCollection) -> Vec<FieldError> {
+    COLLECTION_RULES
+        .iter()
+        .filter_map(|(field, rules)| {
+            let value = field.value(collection);
+            rules
+                .iter()
+                .find_map(|rule| rule.check(field.label(), &value))
+                .map(|message| FieldError {
+                    field: *field,
+                    message,
+                })
+        })
+        .collect()
+}
+
+/// Message for `field` among `errors`, if it has one
+pub fn error_for(errors: &[FieldError], field: Field) -> Option<String> {
+    errors
+        .iter()
+        .find(|error| error.field == field)
+        .map(|error| error.message.clone())
+}
+
+/// Document categories a collection is flagged without
+pub const REQUIRED_DOCUMENTS: &[DocumentCategory] = &[
+    DocumentCategory::Registration,
+    DocumentCategory::Insurance,
+    DocumentCategory::InspectionReport,
+];
+
+/// A required document category that isn't covered
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DocumentFlag {
+    Missing(DocumentCategory),
+    /// Every document of the category has expired
+    Expired(DocumentCategory),
+}
+
+impl DocumentFlag {
+    pub fn message(self) -> String {
+        match self {
+            DocumentFlag::Missing(category) => format!("{} document missing", category.label()),
+            DocumentFlag::Expired(category) => format!("{} document expired", category.label()),
+        }
+    }
+}
+
+/// Required categories with no document, or only expired ones at `now` (nanoseconds)
+pub fn document_flags(documents: &[CollectionDocument], now: u64) -> Vec<DocumentFlag> {
+    REQUIRED_DOCUMENTS
+        .iter()
+        .filter_map(|&category| {
+            let mut expiries = documents
+                .iter()
+                .filter(|document| document.category == category)
+                .map(|document| document.expires_at)
+                .peekable();
+            if expiries.peek().is_none() {
+                Some(DocumentFlag::Missing(category))
+            } else if expiries.all(|expires_at| expires_at.is_some_and(|at| at <= now)) {
+                Some(DocumentFlag::Expired(category))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::collection::CollectionImage;
+
+    fn document(category: DocumentCategory, expires_at: Option<u64>) -> CollectionDocument {
+        CollectionDocument {
+            category,
+            expires_at,
+            ..CollectionDocument::new(format!("/file-{}", category.label()))
+        }
+    }
+
+    fn valid_collection() -> CarCollection {
+        CarCollection {
+            name: "Porsche 911".to_string(),
+            model: "964 (1991)".to_string(),
+            logo: "/img/logo.png".to_string(),
+            images: (0..3)
+                .map(|i| CollectionImage::new(format!("/img/{}.jpg", i)))
+                .collect(),
+            documents: vec![document(DocumentCategory::Registration, None)],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn valid_collection_has_no_errors() {
+        assert!(validate_collection(&valid_collection()).is_empty());
+    }
+
+    #[test]
+    fn reports_first_broken_rule_per_field() {
+        let collection = CarCollection {
+            name: "  ".to_string(),
+            model: "911 <turbo>".to_string(),
+            images: Vec::new(),
+            ..valid_collection()
+        };
+        let errors = validate_collection(&collection);
+
+        assert_eq!(
+            errors.iter().map(|e| e.field).collect::<Vec<_>>(),
+            vec![Field::Name, Field::Model, Field::Images]
+        );
+        assert_eq!(
+            error_for(&errors, Field::Name).as_deref(),
+            Some("Name is required")
+        );
+        assert_eq!(
+            error_for(&errors, Field::Images).as_deref(),
+            Some("Images needs at least 3 file(s), 0 uploaded")
+        );
+        assert_eq!(error_for(&errors, Field::Logo), None);
+    }
+
+    #[test]
+    fn text_rules() {
+        assert!(Rule::Required.check("Name", &Value::Text("")).is_some());
+        assert!(Rule::Required.check("Name", &Value::Text("a")).is_none());
+        // An empty value is left to `Required`
+        assert!(Rule::MinLength(3).check("Name", &Value::Text("")).is_none());
+        assert!(Rule::MinLength(3)
+            .check("Name", &Value::Text("ab"))
+            .is_some());
+        assert!(Rule::MinLength(3)
+            .check("Name", &Value::Text("abc"))
+            .is_none());
+        assert!(Rule::MaxLength(3)
+            .check("Name", &Value::Text("äöü"))
+            .is_none());
+        assert!(Rule::MaxLength(3)
+            .check("Name", &Value::Text("abcd"))
+            .is_some());
+        assert!(Rule::AllowedChars("-.")
+            .check("Model", &Value::Text("A-1.5 x"))
+            .is_none());
+        assert!(Rule::AllowedChars("-.")
+            .check("Model", &Value::Text("A/1"))
+            .is_some());
+    }
+
+    #[test]
+    fn count_rules() {
+        assert_eq!(
+            Rule::MinCount(1).check("Documents", &Value::Count(0)),
+            Some("Documents needs at least 1 file(s), 0 uploaded".to_string())
+        );
+        assert!(Rule::MaxCount(2)
+            .check("Images", &Value::Count(2))
+            .is_none());
+        assert!(Rule::MaxCount(2)
+            .check("Images", &Value::Count(3))
+            .is_some());
+        // Text rules never apply to counts and the other way around
+        assert!(Rule::Required.check("Images", &Value::Count(0)).is_none());
+        assert!(Rule::MinCount(1).check("Name", &Value::Text("")).is_none());
+    }
+
+    #[test]
+    fn flags_missing_categories() {
+        let documents = vec![
+            document(DocumentCategory::Registration, None),
+            document(DocumentCategory::Invoice, None),
+        ];
+
+        assert_eq!(
+            document_flags(&documents, 100),
+            vec![
+                DocumentFlag::Missing(DocumentCategory::Insurance),
+                DocumentFlag::Missing(DocumentCategory::InspectionReport),
+            ]
+        );
+        assert_eq!(document_flags(&[], 100).len(), REQUIRED_DOCUMENTS.len());
+    }
+
+    #[test]
+    fn flags_categories_whose_documents_all_expired() {
+        let mut documents = vec![
+            document(DocumentCategory::Registration, Some(50)),
+            document(DocumentCategory::Registration, Some(100)),
+            document(DocumentCategory::Insurance, Some(50)),
+            document(DocumentCategory::Insurance, Some(150)),
+            document(DocumentCategory::InspectionReport, None),
+        ];
+
+        assert_eq!(
+            document_flags(&documents, 100),
+            vec![DocumentFlag::Expired(DocumentCategory::Registration)]
+        );
+
+        // A document without an expiry keeps its category valid
+        documents.push(document(DocumentCategory::Registration, None));
+        assert!(document_flags(&documents, 100).is_empty());
+    }
+}